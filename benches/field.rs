@@ -0,0 +1,108 @@
+// cargo bench --bench field
+//
+// Compares reducing a product with % against the former modpow(1, p) and against Barrett
+// reduction, and times a secp256k1 scalar multiplication which performs those reductions in
+// its inner loop. Also counts the heap allocations of an inversion with and without a cached
+// p - 2 exponent, and of a whole scalar multiplication.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use ec_cryptography::barrett::Barrett;
+use ec_cryptography::{EllipticCurve, FiniteField, Point};
+use num_bigint::BigUint;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations<F: FnMut()>(name: &str, mut f: F) -> usize {
+    let start = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    let count = ALLOCATIONS.load(Ordering::Relaxed) - start;
+    println!("{name:<40} {count:>12} allocations");
+    count
+}
+
+fn bench<F: FnMut()>(name: &str, iterations: u32, mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let elapsed = start.elapsed() / iterations;
+    println!("{name:<40} {elapsed:>12?}/iter");
+    elapsed
+}
+
+fn main() {
+    let p = BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+        16
+    ).expect("Could not convert p");
+    let n = BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        16
+    ).expect("Could not convert n");
+    let gx = BigUint::parse_bytes(
+        b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        16
+    ).expect("Could not convert gx");
+    let gy = BigUint::parse_bytes(
+        b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        16
+    ).expect("Could not convert gy");
+
+    let one = BigUint::from(1u32);
+    let product = &gx * &gy;
+
+    let modpow = bench("reduce product: modpow(1, p)", 100_000, || {
+        black_box(black_box(&product).modpow(&one, &p));
+    });
+    let rem = bench("reduce product: % p", 100_000, || {
+        black_box(black_box(&product) % &p);
+    });
+    println!("speedup: {:.1}x", modpow.as_secs_f64() / rem.as_secs_f64());
+
+    let barrett = Barrett::new(p.clone());
+    let reduced = bench("reduce product: barrett", 100_000, || {
+        black_box(barrett.reduce(black_box(&product)));
+    });
+    println!("speedup: {:.1}x", rem.as_secs_f64() / reduced.as_secs_f64());
+
+    let p_minus_two = &p - BigUint::from(2u32);
+    allocations("inverse_multiplication", || {
+        black_box(FiniteField::inverse_multiplication(black_box(&gx), &p));
+    });
+    allocations("inverse_multiplication_with_exponent", || {
+        black_box(FiniteField::inverse_multiplication_with_exponent(
+            black_box(&gx),
+            &p,
+            &p_minus_two,
+        ));
+    });
+
+    let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p);
+    let g = Point::Coordinate(gx, gy);
+    bench("secp256k1 scalar_multiplication(n)", 10, || {
+        black_box(ec.scalar_multiplication(&g, black_box(&n)));
+    });
+    allocations("secp256k1 scalar_multiplication(n)", || {
+        black_box(ec.scalar_multiplication(&g, black_box(&n)));
+    });
+}
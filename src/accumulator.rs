@@ -0,0 +1,98 @@
+use core::mem;
+
+use crate::{EllipticCurve, Point};
+
+// A running sum of points on one curve. Each push folds the point into the sum in place, so
+// summing a list of points moves one accumulator along instead of building a new Point from
+// two borrowed ones each time.
+#[derive(Clone, Debug)]
+pub struct PointAccumulator<'a> {
+    ec: &'a EllipticCurve,
+    sum: Point,
+}
+
+impl<'a> PointAccumulator<'a> {
+    pub fn new(ec: &'a EllipticCurve) -> Self {
+        Self {
+            ec,
+            sum: Point::Identity,
+        }
+    }
+
+    pub fn push(&mut self, c: &Point) {
+        assert!(self.ec.is_on_curve(c), "{:?} is not on curve", c);
+
+        // Identity + c = c, so the first push takes a copy of c
+        let sum = mem::replace(&mut self.sum, Point::Identity);
+        self.sum = self.ec.add_unchecked(sum, c);
+    }
+
+    pub fn finish(self) -> Point {
+        self.sum
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_accumulator() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let p = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let r = Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32));
+
+        let mut sum = PointAccumulator::new(&ec);
+        for c in [&p, &q, &r] {
+            sum.push(c);
+        }
+
+        assert_eq!(sum.finish(), ec.add(&ec.add(&p, &q), &r));
+    }
+
+    #[test]
+    fn test_accumulator_identity_and_doubling() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let p = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        assert_eq!(PointAccumulator::new(&ec).finish(), Point::Identity);
+
+        // p, then p again (doubling), then -2p back to Identity, then p
+        let mut sum = PointAccumulator::new(&ec);
+        sum.push(&p);
+        assert_eq!(sum.clone().finish(), p);
+        sum.push(&p);
+        assert_eq!(sum.clone().finish(), ec.double(&p));
+        sum.push(&ec.negate(&ec.double(&p)));
+        assert_eq!(sum.clone().finish(), Point::Identity);
+        sum.push(&Point::Identity);
+        sum.push(&p);
+        assert_eq!(sum.finish(), p);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_accumulator_off_curve() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let c = Point::Coordinate(BigUint::from(5u32), BigUint::from(2u32));
+        PointAccumulator::new(&ec).push(&c);
+    }
+}
@@ -0,0 +1,148 @@
+use core::ops::{Add, Mul};
+
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, Point};
+
+// A point bound to the curve it lives on, so the group law can be written with operators:
+// `&p + &q` and `&p * &k`.
+#[derive(Clone, Debug)]
+pub struct AffinePoint<'a> {
+    ec: &'a EllipticCurve,
+    point: Point,
+}
+
+impl<'a> AffinePoint<'a> {
+    pub fn new(ec: &'a EllipticCurve, point: Point) -> Self {
+        assert!(ec.is_on_curve(&point), "{:?} is not on curve", point);
+        Self { ec, point }
+    }
+
+    pub fn point(&self) -> &Point {
+        &self.point
+    }
+
+    pub fn into_point(self) -> Point {
+        self.point
+    }
+}
+
+impl PartialEq for AffinePoint<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.ec == *other.ec && self.point == other.point
+    }
+}
+
+impl<'a> Add for &AffinePoint<'a> {
+    type Output = AffinePoint<'a>;
+
+    fn add(self, other: &AffinePoint<'a>) -> AffinePoint<'a> {
+        assert!(*self.ec == *other.ec, "points belong to different curves");
+        AffinePoint {
+            ec: self.ec,
+            point: self.ec.add(&self.point, &other.point),
+        }
+    }
+}
+
+impl<'a> Mul<&BigUint> for &AffinePoint<'a> {
+    type Output = AffinePoint<'a>;
+
+    fn mul(self, d: &BigUint) -> AffinePoint<'a> {
+        AffinePoint {
+            ec: self.ec,
+            point: self.ec.scalar_multiplication(&self.point, d),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let wrap = |point: &Point| AffinePoint::new(&ec, point.clone());
+
+        let p = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let q = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        assert_eq!((&wrap(&p) + &wrap(&q)).into_point(), ec.add(&p, &q));
+        assert_eq!((&wrap(&q) + &wrap(&q)).into_point(), ec.double(&q));
+        assert_eq!((&wrap(&p) + &wrap(&Point::Identity)).into_point(), p);
+    }
+
+    #[test]
+    fn test_mul() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = AffinePoint::new(
+            &ec,
+            Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32)),
+        );
+
+        for d in [2u32, 10, 16, 19] {
+            let d = BigUint::from(d);
+            assert_eq!((&a * &d).into_point(), ec.scalar_multiplication(a.point(), &d));
+        }
+
+        // 2 * (5, 1) + 8 * (5, 1) = 10 * (5, 1)
+        let result = &(&a * &BigUint::from(2u32)) + &(&a * &BigUint::from(8u32));
+        assert_eq!(result, &a * &BigUint::from(10u32));
+    }
+
+    #[test]
+    fn test_separately_built_curves() {
+        // y^2 = x^3 + 2x + 2 mod 17, built twice
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let ec_copy = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let ec_clone = ec.clone();
+
+        let p = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let q = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        for other in [&ec_copy, &ec_clone] {
+            let a = AffinePoint::new(&ec, p.clone());
+            let b = AffinePoint::new(other, q.clone());
+            assert_eq!(a, AffinePoint::new(other, p.clone()));
+            assert_eq!((&a + &b).into_point(), ec.add(&p, &q));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_different_curves() {
+        // y^2 = x^3 + 2x + 2 mod 17 and y^2 = x^3 + 3x + 2 mod 17, which both contain (0, 6)
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let other = EllipticCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let p = Point::Coordinate(BigUint::from(0u32), BigUint::from(6u32));
+        let _ = &AffinePoint::new(&ec, p.clone()) + &AffinePoint::new(&other, p);
+    }
+}
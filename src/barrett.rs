@@ -0,0 +1,111 @@
+use num_bigint::BigUint;
+
+// Barrett reduction for a fixed modulus p of k bits
+//
+// mu = floor(2^(2k) / p) is computed once, then for x < 2^(2k):
+//
+// q = ((x >> (k - 1)) * mu) >> (k + 1)
+// r = x - q * p, which is less than 3p
+//
+// With num-bigint every intermediate is a fresh heap allocation, and measured on the
+// secp256k1 modulus this is slower than `x % p`, so FiniteField keeps using `%`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Barrett {
+    p: BigUint,
+    mu: BigUint,
+    k: u64,
+}
+
+impl Barrett {
+    pub fn new(p: BigUint) -> Self {
+        assert!(p > BigUint::from(1u32), "{p} <= 1");
+
+        let k = p.bits();
+        let mu = (BigUint::from(1u32) << (2 * k)) / &p;
+        Self { p, mu, k }
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn reduce(&self, x: &BigUint) -> BigUint {
+        // x mod p
+        assert!(x.bits() <= 2 * self.k, "{x} >= 2^{}", 2 * self.k);
+
+        let q = ((x >> (self.k - 1)) * &self.mu) >> (self.k + 1);
+        let mut r = x - q * &self.p;
+        while r >= self.p {
+            r -= &self.p;
+        }
+        r
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reduce_small_modulus() {
+        let p = BigUint::from(17u32);
+        let barrett = Barrett::new(p.clone());
+
+        for c in 0u32..17 {
+            for d in 0u32..17 {
+                let x = BigUint::from(c * d);
+                assert_eq!(barrett.reduce(&x), &x % &p, "{c} * {d}");
+            }
+        }
+        // largest input 2^10 - 1
+        let x = BigUint::from(1023u32);
+        assert_eq!(barrett.reduce(&x), &x % &p);
+    }
+
+    #[test]
+    fn test_reduce_secp256k1_products() {
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let barrett = Barrett::new(p.clone());
+
+        let mut c = BigUint::from(3u32);
+        let mut d = &p - BigUint::from(1u32);
+        for _ in 0..500 {
+            let x = &c * &d;
+            assert_eq!(barrett.reduce(&x), &x % &p);
+
+            c = (&c * &c + 7u32) % &p;
+            d = (&d * &c + 11u32) % &p;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_reduce_random_products() {
+        use num_bigint::RandBigInt;
+
+        let mut rng = rand::thread_rng();
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+            16
+        ).expect("Could not convert p");
+        let barrett = Barrett::new(p.clone());
+
+        for _ in 0..1000 {
+            let c = rng.gen_biguint_below(&p);
+            let d = rng.gen_biguint_below(&p);
+            let x = &c * &d;
+            assert_eq!(barrett.reduce(&x), &x % &p);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reduce_input_too_large() {
+        let barrett = Barrett::new(BigUint::from(17u32));
+
+        barrett.reduce(&BigUint::from(1024u32));
+    }
+}
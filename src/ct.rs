@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::Point;
+
+// Constant-time building blocks for Point. Both points are laid out as byte strings of a
+// fixed width taken from the caller (tag || x || y, zero padded) and combined byte by byte
+// without branching on the secret choice. The width is normally the byte length of the curve
+// modulus, so the encoding length is the same for every point on the curve.
+//
+// The conversions to and from BigUint are not themselves constant-time in the coordinate
+// values, and rebuilding the Point enum at the end has to branch on the selected tag.
+
+impl Point {
+    pub fn conditional_select(a: &Point, b: &Point, choice: bool, width: usize) -> Point {
+        // a if choice is false, b if choice is true
+        let choice = Choice::from(choice as u8);
+
+        let a_bytes = ct_bytes(a, width);
+        let b_bytes = ct_bytes(b, width);
+        let selected: Vec<u8> = a_bytes
+            .iter()
+            .zip(b_bytes.iter())
+            .map(|(a, b)| u8::conditional_select(a, b, choice))
+            .collect();
+
+        from_ct_bytes(&selected, width)
+    }
+
+    pub fn ct_eq(&self, other: &Point, width: usize) -> Choice {
+        // compares every byte of both encodings, whatever the position of the first difference
+        ct_bytes(self, width).ct_eq(&ct_bytes(other, width))
+    }
+}
+
+fn ct_bytes(point: &Point, width: usize) -> Vec<u8> {
+    // Identity => 0x00 || 0..0 || 0..0
+    // Coordinate(x, y) => 0x01 || x || y
+    let mut bytes = Vec::with_capacity(1 + 2 * width);
+    match point {
+        Point::Coordinate(x, y) => {
+            bytes.push(0x01);
+            for coordinate in [x, y] {
+                let coordinate = coordinate.to_bytes_be();
+                assert!(coordinate.len() <= width, "coordinate wider than {width} bytes");
+                bytes.resize(bytes.len() + width - coordinate.len(), 0);
+                bytes.extend_from_slice(&coordinate);
+            }
+        }
+        Point::Identity => bytes.resize(1 + 2 * width, 0),
+    }
+    bytes
+}
+
+fn from_ct_bytes(bytes: &[u8], width: usize) -> Point {
+    // both coordinates are decoded whatever the tag, so the tag only decides which variant
+    // is returned
+    let x = BigUint::from_bytes_be(&bytes[1..1 + width]);
+    let y = BigUint::from_bytes_be(&bytes[1 + width..]);
+    let is_coordinate = bytes[0].ct_eq(&0x01);
+
+    if bool::from(is_coordinate) {
+        Point::Coordinate(x, y)
+    } else {
+        Point::Identity
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // byte length of the largest test coordinate, 0x1234
+    const WIDTH: usize = 2;
+
+    #[test]
+    fn test_conditional_select() {
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let b = Point::Coordinate(BigUint::from(0x1234u32), BigUint::from(0u32));
+
+        assert_eq!(Point::conditional_select(&a, &b, false, WIDTH), a);
+        assert_eq!(Point::conditional_select(&a, &b, true, WIDTH), b);
+        assert_eq!(Point::conditional_select(&b, &a, false, WIDTH), b);
+        assert_eq!(Point::conditional_select(&b, &a, true, WIDTH), a);
+        assert_eq!(Point::conditional_select(&a, &a, true, WIDTH), a);
+    }
+
+    #[test]
+    fn test_conditional_select_identity() {
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        assert_eq!(Point::conditional_select(&a, &Point::Identity, false, WIDTH), a);
+        assert_eq!(
+            Point::conditional_select(&a, &Point::Identity, true, WIDTH),
+            Point::Identity
+        );
+        assert_eq!(
+            Point::conditional_select(&Point::Identity, &a, false, WIDTH),
+            Point::Identity
+        );
+        assert_eq!(Point::conditional_select(&Point::Identity, &a, true, WIDTH), a);
+        assert_eq!(
+            Point::conditional_select(&Point::Identity, &Point::Identity, true, WIDTH),
+            Point::Identity
+        );
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let b = Point::Coordinate(BigUint::from(5u32), BigUint::from(16u32));
+        let c = Point::Coordinate(BigUint::from(0x1234u32), BigUint::from(1u32));
+
+        assert!(bool::from(a.ct_eq(&a.clone(), WIDTH)));
+        assert!(!bool::from(a.ct_eq(&b, WIDTH)));
+        assert!(!bool::from(a.ct_eq(&c, WIDTH)));
+        assert!(!bool::from(c.ct_eq(&a, WIDTH)));
+    }
+
+    #[test]
+    fn test_ct_eq_identity() {
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let zero = Point::Coordinate(BigUint::from(0u32), BigUint::from(0u32));
+
+        assert!(bool::from(Point::Identity.ct_eq(&Point::Identity, WIDTH)));
+        assert!(!bool::from(Point::Identity.ct_eq(&a, WIDTH)));
+        assert!(!bool::from(a.ct_eq(&Point::Identity, WIDTH)));
+        // (0, 0) must not be confused with Identity
+        assert!(!bool::from(Point::Identity.ct_eq(&zero, WIDTH)));
+        assert!(!bool::from(zero.ct_eq(&Point::Identity, WIDTH)));
+    }
+
+    #[test]
+    fn test_ct_bytes_fixed_width() {
+        // byte length of p = 2^16 + 1; every encoding is 1 + 2 * 3 bytes whatever the values
+        let width = crate::byte_length(&BigUint::from(65537u32));
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let b = Point::Coordinate(BigUint::from(0x10000u32), BigUint::from(0u32));
+
+        for point in [&a, &b, &Point::Identity] {
+            assert_eq!(ct_bytes(point, width).len(), 1 + 2 * width);
+        }
+        assert_eq!(Point::conditional_select(&a, &b, true, width), b);
+        assert!(!bool::from(a.ct_eq(&b, width)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ct_eq_too_narrow() {
+        let a = Point::Coordinate(BigUint::from(0x1234u32), BigUint::from(1u32));
+
+        let _ = a.ct_eq(&a, 1);
+    }
+
+    #[test]
+    fn test_conditional_select_zero_coordinates() {
+        // (0, 0) must not be confused with Identity
+        let a = Point::Coordinate(BigUint::from(0u32), BigUint::from(0u32));
+
+        assert_eq!(Point::conditional_select(&Point::Identity, &a, true, WIDTH), a);
+        assert_eq!(Point::conditional_select(&a, &Point::Identity, false, WIDTH), a);
+    }
+}
@@ -0,0 +1,312 @@
+use num_bigint::{BigInt, BigUint};
+
+use crate::{EllipticCurve, Point};
+
+// A curve paired with a generator and the order of that generator
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurveWithGenerator {
+    curve: EllipticCurve,
+    generator: Point,
+    order: BigUint,
+}
+
+impl CurveWithGenerator {
+    pub fn new(curve: EllipticCurve, generator: Point, order: BigUint) -> CurveWithGenerator {
+        assert!(curve.is_on_curve(&generator), "{:?} is not on curve", generator);
+
+        CurveWithGenerator { curve, generator, order }
+    }
+
+    pub fn curve(&self) -> &EllipticCurve {
+        &self.curve
+    }
+
+    pub fn generator(&self) -> &Point {
+        &self.generator
+    }
+
+    pub fn order(&self) -> &BigUint {
+        &self.order
+    }
+
+    pub fn mul_generator(&self, scalar: &BigUint) -> Point {
+        // k * G
+        self.curve.scalar_multiplication(&self.generator, scalar)
+    }
+}
+
+impl From<(EllipticCurve, Point, BigUint)> for CurveWithGenerator {
+    fn from((curve, generator, order): (EllipticCurve, Point, BigUint)) -> CurveWithGenerator {
+        CurveWithGenerator::new(curve, generator, order)
+    }
+}
+
+// A named curve's full domain parameters: a CurveWithGenerator together with the cofactor
+// h = #E / n of the generator's order n
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurveParams {
+    curve: CurveWithGenerator,
+    cofactor: BigUint,
+}
+
+impl CurveParams {
+    pub fn new(
+        curve: EllipticCurve,
+        generator: Point,
+        order: BigUint,
+        cofactor: BigUint,
+    ) -> CurveParams {
+        CurveParams {
+            curve: CurveWithGenerator::new(curve, generator, order),
+            cofactor,
+        }
+    }
+
+    pub fn secp256k1() -> CurveParams {
+        let (curve, generator, order) = secp256k1();
+        CurveParams::new(curve, generator, order, BigUint::from(1u32))
+    }
+
+    pub fn p256() -> CurveParams {
+        let (curve, generator, order) = p256();
+        CurveParams::new(curve, generator, order, BigUint::from(1u32))
+    }
+
+    pub fn curve_with_generator(&self) -> &CurveWithGenerator {
+        &self.curve
+    }
+
+    pub fn curve(&self) -> &EllipticCurve {
+        self.curve.curve()
+    }
+
+    pub fn generator(&self) -> &Point {
+        self.curve.generator()
+    }
+
+    pub fn order(&self) -> &BigUint {
+        self.curve.order()
+    }
+
+    pub fn cofactor(&self) -> &BigUint {
+        &self.cofactor
+    }
+}
+
+impl From<CurveParams> for CurveWithGenerator {
+    fn from(params: CurveParams) -> CurveWithGenerator {
+        params.curve
+    }
+}
+
+// Named curves as (curve, generator, order of the generator)
+
+pub fn secp256k1() -> (EllipticCurve, Point, BigUint) {
+    /*
+        secp256k1
+
+        y^2 = x^3 + 7 mod p
+    */
+    let p = BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+        16
+    ).expect("Could not convert p");
+    let n = BigUint::parse_bytes(
+        b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+        16
+    ).expect("Could not convert n");
+    let gx = BigUint::parse_bytes(
+        b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        16
+    ).expect("Could not convert gx");
+    let gy = BigUint::parse_bytes(
+        b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        16
+    ).expect("Could not convert gy");
+
+    (
+        EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p),
+        Point::Coordinate(gx, gy),
+        n,
+    )
+}
+
+pub fn p256() -> (EllipticCurve, Point, BigUint) {
+    /*
+        NIST P-256 (secp256r1)
+
+        y^2 = x^3 - 3x + b mod p
+    */
+    let p = BigUint::parse_bytes(
+        b"FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+        16
+    ).expect("Could not convert p");
+    let b = BigInt::parse_bytes(
+        b"5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+        16
+    ).expect("Could not convert b");
+    let n = BigUint::parse_bytes(
+        b"FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+        16
+    ).expect("Could not convert n");
+    let gx = BigUint::parse_bytes(
+        b"6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+        16
+    ).expect("Could not convert gx");
+    let gy = BigUint::parse_bytes(
+        b"4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+        16
+    ).expect("Could not convert gy");
+
+    (
+        EllipticCurve::from_signed(BigInt::from(-3), b, p),
+        Point::Coordinate(gx, gy),
+        n,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schnorr;
+
+    #[test]
+    fn test_secp256k1_order() {
+        // n * G = Point::Identity
+        let (ec, g, n) = secp256k1();
+
+        assert!(ec.is_on_curve(&g));
+        assert_eq!(ec.scalar_multiplication(&g, &n), Point::Identity);
+    }
+
+    #[test]
+    fn test_mul_generator() {
+        let curve = CurveWithGenerator::from(secp256k1());
+        let (ec, g, n) = secp256k1();
+        assert_eq!(*curve.curve(), ec);
+        assert_eq!(*curve.generator(), g);
+        assert_eq!(*curve.order(), n);
+
+        let k = BigUint::parse_bytes(
+            b"AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522",
+            16
+        ).expect("Could not convert k");
+        for k in [BigUint::from(1u32), BigUint::from(2u32), k, &n - BigUint::from(1u32)] {
+            assert_eq!(curve.mul_generator(&k), ec.scalar_multiplication(&g, &k));
+        }
+        assert_eq!(curve.mul_generator(&n), Point::Identity);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_curve_with_generator_off_curve() {
+        let (ec, _, n) = secp256k1();
+
+        CurveWithGenerator::new(ec, Point::Coordinate(BigUint::from(1u32), BigUint::from(1u32)), n);
+    }
+
+    #[test]
+    fn test_curve_params() {
+        let params = CurveParams::secp256k1();
+        let (ec, g, n) = secp256k1();
+        assert_eq!(*params.curve(), ec);
+        assert_eq!(*params.generator(), g);
+        assert_eq!(*params.order(), n);
+        assert_eq!(*params.cofactor(), BigUint::from(1u32));
+        assert_eq!(*params.curve_with_generator(), CurveWithGenerator::from(secp256k1()));
+
+        let params = CurveParams::p256();
+        let (ec, g, n) = p256();
+        assert_eq!(*params.curve(), ec);
+        assert_eq!(*params.generator(), g);
+        assert_eq!(*params.order(), n);
+        assert_eq!(*params.cofactor(), BigUint::from(1u32));
+        assert_eq!(CurveWithGenerator::from(params), CurveWithGenerator::from(p256()));
+    }
+
+    #[test]
+    fn test_curve_params_cofactor() {
+        // y^2 = x^3 + 2x + 3 mod 17 has 22 points; (3, 6) generates the subgroup of order 11
+        let params = CurveParams::new(
+            EllipticCurve::new(
+                BigUint::from(2u32),
+                BigUint::from(3u32),
+                BigUint::from(17u32),
+            ),
+            Point::Coordinate(BigUint::from(3u32), BigUint::from(6u32)),
+            BigUint::from(11u32),
+            BigUint::from(2u32),
+        );
+
+        assert_eq!(
+            params.order() * params.cofactor(),
+            BigUint::from(params.curve().points().count())
+        );
+        assert_eq!(params.curve_with_generator().mul_generator(params.order()), Point::Identity);
+    }
+
+    #[test]
+    fn test_curve_params_schnorr() {
+        let params = CurveParams::secp256k1();
+        let (ec, g, n) = (params.curve(), params.generator(), params.order());
+
+        let private_key = BigUint::from(0xDEADBEEFu32);
+        let public_key = ec.scalar_multiplication(g, &private_key);
+        let message = b"Bob -> 1 BTC -> Alice";
+
+        let signature = schnorr::sign(ec, g, n, &private_key, message, &BigUint::from(12345u32));
+        assert!(schnorr::verify(ec, g, n, &public_key, message, &signature));
+    }
+
+    #[test]
+    fn test_p256_generator() {
+        let (ec, g, _) = p256();
+
+        assert!(ec.is_on_curve(&g));
+        assert_eq!(*ec.a(), ec.modulus() - BigUint::from(3u32));
+
+        // 2 * G
+        let x = BigUint::parse_bytes(
+            b"7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+            16
+        ).expect("Could not convert x");
+        let y = BigUint::parse_bytes(
+            b"07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1",
+            16
+        ).expect("Could not convert y");
+        assert_eq!(ec.double(&g), Point::Coordinate(x, y));
+    }
+
+    #[test]
+    fn test_p256_order() {
+        // n * G = Point::Identity
+        let (ec, g, n) = p256();
+
+        let result = ec.scalar_multiplication(&g, &n);
+
+        assert_eq!(result, Point::Identity);
+    }
+
+    #[test]
+    fn test_p256_schnorr() {
+        // sign and verify over a = -3, going through add, double and scalar multiplication
+        let (ec, g, n) = p256();
+
+        let private_key = BigUint::parse_bytes(
+            b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+            16
+        ).expect("Could not convert private_key");
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        let message = b"Bob -> 1 BTC -> Alice";
+        let k = BigUint::parse_bytes(
+            b"A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            16
+        ).expect("Could not convert k");
+
+        let signature = schnorr::sign(&ec, &g, &n, &private_key, message, &k);
+        assert!(schnorr::verify(&ec, &g, &n, &public_key, message, &signature));
+        assert!(!schnorr::verify(&ec, &g, &n, &public_key, b"Bob -> 2 BTC -> Alice", &signature));
+    }
+}
@@ -0,0 +1,71 @@
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, Point};
+
+pub fn encrypt(
+    ec: &EllipticCurve,
+    generator: &Point,
+    public_key: &Point,
+    message_point: &Point,
+    k: &BigUint,
+) -> (Point, Point) {
+    // C1 = k * G
+    // C2 = M + k * B
+    let c1 = ec.scalar_multiplication(generator, k);
+    let shared = ec.scalar_multiplication(public_key, k);
+    let c2 = ec.add(message_point, &shared);
+    (c1, c2)
+}
+
+pub fn decrypt(ec: &EllipticCurve, private_key: &BigUint, ciphertext: &(Point, Point)) -> Point {
+    // M = C2 - d * C1
+    let (c1, c2) = ciphertext;
+    let shared = ec.scalar_multiplication(c1, private_key);
+    ec.subtract(c2, &shared)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        let private_key = BigUint::from(7u32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+
+        for m in [1u32, 3, 10, 18] {
+            let message_point = ec.scalar_multiplication(&g, &BigUint::from(m));
+            for k in [2u32, 5, 10, 13] {
+                let ciphertext =
+                    encrypt(&ec, &g, &public_key, &message_point, &BigUint::from(k));
+                assert_ne!(ciphertext.1, message_point);
+                assert_eq!(decrypt(&ec, &private_key, &ciphertext), message_point);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decrypt_wrong_key() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        let public_key = ec.scalar_multiplication(&g, &BigUint::from(7u32));
+        let message_point = ec.scalar_multiplication(&g, &BigUint::from(3u32));
+
+        let ciphertext = encrypt(&ec, &g, &public_key, &message_point, &BigUint::from(5u32));
+        assert_ne!(decrypt(&ec, &BigUint::from(8u32), &ciphertext), message_point);
+    }
+}
@@ -0,0 +1,31 @@
+use core::fmt;
+
+use num_bigint::BigUint;
+
+use crate::Point;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EcError {
+    // the point has a coordinate >= p or does not satisfy the curve equation
+    NotOnCurve(Point),
+    // Identity was given where an affine point is needed, e.g. a generator or public key
+    PointIsIdentity,
+    // a private key or nonce is not in [1, q)
+    ScalarOutOfRange(BigUint),
+    // the signature is malformed or does not verify
+    InvalidSignature,
+}
+
+impl fmt::Display for EcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EcError::NotOnCurve(point) => write!(f, "{point} is not on curve"),
+            EcError::PointIsIdentity => write!(f, "point is the identity"),
+            EcError::ScalarOutOfRange(d) => write!(f, "{d} is not in [1, q)"),
+            EcError::InvalidSignature => write!(f, "invalid signature"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EcError {}
@@ -0,0 +1,107 @@
+use core::fmt::Debug;
+
+use num_bigint::BigUint;
+
+use crate::FiniteField;
+
+// The arithmetic EllipticCurve needs from its coordinates. Elements are operated on together
+// with the field they belong to, so a type such as BigUint that does not carry its modulus
+// around can still implement it, with the modulus kept once on the curve.
+pub trait FieldElement: Clone + Debug + PartialEq + Eq {
+    type Field: Clone + Debug + PartialEq + Eq;
+
+    fn add(&self, other: &Self, field: &Self::Field) -> Self;
+    fn mul(&self, other: &Self, field: &Self::Field) -> Self;
+    // multiplicative inverse of a non-zero element
+    fn inverse(&self, field: &Self::Field) -> Self;
+    fn neg(&self, field: &Self::Field) -> Self;
+    fn zero(field: &Self::Field) -> Self;
+    fn one(field: &Self::Field) -> Self;
+
+    fn sub(&self, other: &Self, field: &Self::Field) -> Self {
+        self.add(&other.neg(field), field)
+    }
+
+    // whether self is an element of field, e.g. a BigUint reduced mod p
+    fn belongs_to(&self, _field: &Self::Field) -> bool {
+        true
+    }
+}
+
+// F_p with elements as plain BigUint in [0, p), the field of EllipticCurve::new
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrimeField {
+    pub(crate) p: BigUint,
+    // p - 2, the Fermat inversion exponent
+    pub(crate) p_minus_two: BigUint,
+}
+
+impl PrimeField {
+    pub fn new(p: BigUint) -> Self {
+        let p_minus_two = if p >= BigUint::from(2u32) {
+            &p - BigUint::from(2u32)
+        } else {
+            BigUint::from(0u32)
+        };
+        Self { p, p_minus_two }
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.p
+    }
+}
+
+impl FieldElement for BigUint {
+    type Field = PrimeField;
+
+    // add, mul and neg reduce whatever they are given, so values >= p may be passed in
+    fn add(&self, other: &BigUint, field: &PrimeField) -> BigUint {
+        (self + other) % &field.p
+    }
+
+    fn mul(&self, other: &BigUint, field: &PrimeField) -> BigUint {
+        (self * other) % &field.p
+    }
+
+    fn inverse(&self, field: &PrimeField) -> BigUint {
+        // inverting with the cached p - 2
+        FiniteField::inverse_multiplication_with_exponent(self, &field.p, &field.p_minus_two)
+    }
+
+    fn neg(&self, field: &PrimeField) -> BigUint {
+        (&field.p - self % &field.p) % &field.p
+    }
+
+    fn zero(_field: &PrimeField) -> BigUint {
+        BigUint::from(0u32)
+    }
+
+    fn one(field: &PrimeField) -> BigUint {
+        BigUint::from(1u32) % &field.p
+    }
+
+    fn belongs_to(&self, field: &PrimeField) -> bool {
+        *self < field.p
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_prime_field() {
+        let field = PrimeField::new(BigUint::from(17u32));
+        let c = BigUint::from(5u32);
+
+        assert_eq!(c.mul(&c.inverse(&field), &field), BigUint::one(&field));
+        assert_eq!(c.add(&c.neg(&field), &field), BigUint::zero(&field));
+        assert_eq!(c.sub(&BigUint::from(7u32), &field), BigUint::from(15u32));
+        assert!(c.belongs_to(&field));
+        assert!(!BigUint::from(17u32).belongs_to(&field));
+
+        // values >= p are reduced
+        assert_eq!(BigUint::from(22u32).neg(&field), BigUint::from(12u32));
+        assert_eq!(BigUint::from(40u32).sub(&BigUint::from(3u32), &field), BigUint::from(3u32));
+    }
+}
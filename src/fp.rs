@@ -0,0 +1,231 @@
+use alloc::rc::Rc;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_bigint::BigUint;
+
+use crate::field::FieldElement;
+use crate::FiniteField;
+
+// An element of the prime field F_p. The modulus is shared between elements of the same
+// field, and operators panic when mixing elements of different fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fp {
+    value: BigUint,
+    p: Rc<BigUint>,
+}
+
+impl Fp {
+    pub fn new(value: BigUint, p: Rc<BigUint>) -> Self {
+        let value = value % p.as_ref();
+        Self { value, p }
+    }
+
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.p
+    }
+
+    fn with_value(&self, value: BigUint) -> Self {
+        Self {
+            value,
+            p: Rc::clone(&self.p),
+        }
+    }
+
+    fn assert_same_field(&self, other: &Fp) {
+        assert_eq!(self.p, other.p, "elements belong to different fields");
+    }
+}
+
+impl Add for &Fp {
+    type Output = Fp;
+
+    fn add(self, other: &Fp) -> Fp {
+        self.assert_same_field(other);
+        self.with_value(FiniteField::add(&self.value, &other.value, &self.p))
+    }
+}
+
+impl Sub for &Fp {
+    type Output = Fp;
+
+    fn sub(self, other: &Fp) -> Fp {
+        self.assert_same_field(other);
+        self.with_value(FiniteField::subtract(&self.value, &other.value, &self.p))
+    }
+}
+
+impl Mul for &Fp {
+    type Output = Fp;
+
+    fn mul(self, other: &Fp) -> Fp {
+        self.assert_same_field(other);
+        self.with_value(FiniteField::multiplication(&self.value, &other.value, &self.p))
+    }
+}
+
+impl Div for &Fp {
+    type Output = Fp;
+
+    fn div(self, other: &Fp) -> Fp {
+        self.assert_same_field(other);
+        self.with_value(FiniteField::divide(&self.value, &other.value, &self.p))
+    }
+}
+
+impl Neg for &Fp {
+    type Output = Fp;
+
+    fn neg(self) -> Fp {
+        self.with_value(FiniteField::inverse_addition(&self.value, &self.p))
+    }
+}
+
+impl Add for Fp {
+    type Output = Fp;
+
+    fn add(self, other: Fp) -> Fp {
+        &self + &other
+    }
+}
+
+impl Sub for Fp {
+    type Output = Fp;
+
+    fn sub(self, other: Fp) -> Fp {
+        &self - &other
+    }
+}
+
+impl Mul for Fp {
+    type Output = Fp;
+
+    fn mul(self, other: Fp) -> Fp {
+        &self * &other
+    }
+}
+
+impl Div for Fp {
+    type Output = Fp;
+
+    fn div(self, other: Fp) -> Fp {
+        &self / &other
+    }
+}
+
+impl Neg for Fp {
+    type Output = Fp;
+
+    fn neg(self) -> Fp {
+        -&self
+    }
+}
+
+impl FieldElement for Fp {
+    // the field of an Fp is its shared modulus
+    type Field = Rc<BigUint>;
+
+    fn add(&self, other: &Fp, _field: &Rc<BigUint>) -> Fp {
+        self + other
+    }
+
+    fn mul(&self, other: &Fp, _field: &Rc<BigUint>) -> Fp {
+        self * other
+    }
+
+    fn inverse(&self, _field: &Rc<BigUint>) -> Fp {
+        self.with_value(FiniteField::inverse_multiplication(&self.value, &self.p))
+    }
+
+    fn neg(&self, _field: &Rc<BigUint>) -> Fp {
+        -self
+    }
+
+    fn sub(&self, other: &Fp, _field: &Rc<BigUint>) -> Fp {
+        self - other
+    }
+
+    fn zero(field: &Rc<BigUint>) -> Fp {
+        Fp::new(BigUint::from(0u32), Rc::clone(field))
+    }
+
+    fn one(field: &Rc<BigUint>) -> Fp {
+        Fp::new(BigUint::from(1u32), Rc::clone(field))
+    }
+
+    fn belongs_to(&self, field: &Rc<BigUint>) -> bool {
+        self.p == *field
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new_reduces() {
+        let p = Rc::new(BigUint::from(17u32));
+
+        let c = Fp::new(BigUint::from(40u32), Rc::clone(&p));
+
+        assert_eq!(*c.value(), BigUint::from(6u32));
+        assert_eq!(*c.modulus(), BigUint::from(17u32));
+    }
+
+    #[test]
+    fn test_operations_match_finite_field() {
+        let p = BigUint::from(17u32);
+        let modulus = Rc::new(p.clone());
+
+        for c in 0u32..17 {
+            for d in 0u32..17 {
+                let (c, d) = (BigUint::from(c), BigUint::from(d));
+                let fc = Fp::new(c.clone(), Rc::clone(&modulus));
+                let fd = Fp::new(d.clone(), Rc::clone(&modulus));
+
+                assert_eq!(*(&fc + &fd).value(), FiniteField::add(&c, &d, &p));
+                assert_eq!(*(&fc - &fd).value(), FiniteField::subtract(&c, &d, &p));
+                assert_eq!(*(&fc * &fd).value(), FiniteField::multiplication(&c, &d, &p));
+                if d != BigUint::from(0u32) {
+                    assert_eq!(*(&fc / &fd).value(), FiniteField::divide(&c, &d, &p));
+                }
+                assert_eq!(*(-&fc).value(), FiniteField::inverse_addition(&c, &p));
+            }
+        }
+    }
+
+    #[test]
+    fn test_owned_operations() {
+        let p = Rc::new(BigUint::from(11u32));
+        let c = Fp::new(BigUint::from(4u32), Rc::clone(&p));
+        let d = Fp::new(BigUint::from(10u32), Rc::clone(&p));
+
+        // (4 + 10) * 4 / 10 - (-4) = 3 * 4 * 10 + 4 = 124 = 3 mod 11
+        let r = (c.clone() + d.clone()) * c.clone() / d - (-c);
+
+        assert_eq!(*r.value(), BigUint::from(3u32));
+    }
+
+    #[test]
+    fn test_field_element() {
+        let p = Rc::new(BigUint::from(17u32));
+        let c = Fp::new(BigUint::from(5u32), Rc::clone(&p));
+
+        assert_eq!(FieldElement::mul(&c, &c.inverse(&p), &p), Fp::one(&p));
+        assert_eq!(FieldElement::add(&c, &FieldElement::neg(&c, &p), &p), Fp::zero(&p));
+        assert!(c.belongs_to(&p));
+        assert!(!c.belongs_to(&Rc::new(BigUint::from(19u32))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_different_fields() {
+        let c = Fp::new(BigUint::from(4u32), Rc::new(BigUint::from(11u32)));
+        let d = Fp::new(BigUint::from(4u32), Rc::new(BigUint::from(13u32)));
+
+        let _ = &c + &d;
+    }
+}
@@ -0,0 +1,98 @@
+// Exhaustive group-law checks for small curves: every combination of points yielded by
+// `EllipticCurve::points` is run through the addition law.
+
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, Point};
+
+pub(crate) fn check_group_law(ec: &EllipticCurve) {
+    let points: Vec<Point> = ec.points().collect();
+
+    // sums[i][j] = points[i] + points[j], so associativity below only looks sums up
+    let index = |c: &Point| {
+        points
+            .iter()
+            .position(|p| p == c)
+            .unwrap_or_else(|| panic!("{:?} is not one of the points", c))
+    };
+    let sums: Vec<Vec<usize>> = points
+        .iter()
+        .map(|p| points.iter().map(|q| index(&ec.add(p, q))).collect())
+        .collect();
+
+    for (i, p) in points.iter().enumerate() {
+        // P + O == P
+        assert_eq!(ec.add(p, &Point::Identity), *p, "{:?} + O", p);
+        assert_eq!(ec.add(&Point::Identity, p), *p, "O + {:?}", p);
+
+        // P + (-P) == O
+        assert_eq!(ec.add(p, &ec.negate(p)), Point::Identity, "{:?} + -{:?}", p, p);
+
+        for (j, q) in points.iter().enumerate() {
+            // P + Q == Q + P
+            assert_eq!(sums[i][j], sums[j][i], "{:?} + {:?}", p, q);
+
+            for (k, r) in points.iter().enumerate() {
+                // (P + Q) + R == P + (Q + R)
+                assert_eq!(
+                    sums[sums[i][j]][k],
+                    sums[i][sums[j][k]],
+                    "({:?} + {:?}) + {:?}",
+                    p,
+                    q,
+                    r
+                );
+            }
+        }
+    }
+
+    // d * P agrees with d repeated additions of P
+    let order = BigUint::from(points.len());
+    for p in &points {
+        let mut sum = Point::Identity;
+        let mut d = BigUint::from(0u32);
+        while d <= order {
+            assert_eq!(ec.scalar_multiplication(p, &d), sum, "{} * {:?}", d, p);
+            sum = ec.add(&sum, p);
+            d += 1u32;
+        }
+    }
+}
+
+#[test]
+fn test_group_law_mod_17() {
+    // y^2 = x^3 + 2x + 2 mod 17
+    let ec = EllipticCurve::new(
+        BigUint::from(2u32),
+        BigUint::from(2u32),
+        BigUint::from(17u32),
+    );
+
+    check_group_law(&ec);
+}
+
+#[test]
+fn test_group_law_mod_17_zero_slope_doubling() {
+    // y^2 = x^3 + 3x mod 17, where doubling (4, 5) has slope 0
+    let ec = EllipticCurve::new(
+        BigUint::from(3u32),
+        BigUint::from(0u32),
+        BigUint::from(17u32),
+    );
+
+    check_group_law(&ec);
+}
+
+#[test]
+fn test_group_law_mod_17_with_cofactor() {
+    // y^2 = x^3 + 2x + 3 mod 17
+    let ec = EllipticCurve::new(
+        BigUint::from(2u32),
+        BigUint::from(3u32),
+        BigUint::from(17u32),
+    );
+
+    check_group_law(&ec);
+}
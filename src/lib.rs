@@ -1,70 +1,389 @@
-use num_bigint::BigUint;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+#[cfg(feature = "std")]
+use num_bigint::RandBigInt;
+use num_bigint::{BigInt, BigUint, Sign};
+#[cfg(feature = "std")]
+use rand::Rng;
+use sha2::{Digest, Sha256};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::accumulator::PointAccumulator;
+use crate::field::{FieldElement, PrimeField};
+
+pub mod accumulator;
+pub mod affine;
+pub mod barrett;
+#[cfg(feature = "subtle")]
+mod ct;
+pub mod curves;
+pub mod ecelgamal;
+mod error;
+pub mod field;
+pub mod fp;
+#[cfg(test)]
+mod group_law;
+pub mod montgomery;
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_smoke;
+pub mod pedersen;
+pub mod projective;
+pub mod scalar;
+pub mod schnorr;
+#[cfg(feature = "serde")]
+mod serde_hex;
+#[cfg(all(test, feature = "std"))]
+mod timing;
+pub mod trace;
+
+pub use error::EcError;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Point<F = BigUint> {
+    Coordinate(F, F),
+    Identity,
+}
 
-#[derive(Clone, Debug, PartialEq)]
-pub enum Point {
-    Coordinate(BigUint, BigUint),
+// the serialized form of Point over BigUint, with each coordinate as hex
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Point", rename = "Point")]
+enum PointDef {
+    Coordinate(
+        #[serde(with = "serde_hex")] BigUint,
+        #[serde(with = "serde_hex")] BigUint,
+    ),
     Identity,
 }
-pub struct EllipticCurve {
+
+#[cfg(feature = "serde")]
+impl Serialize for Point {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PointDef::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Point {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PointDef::deserialize(deserializer)
+    }
+}
+
+impl Point {
+    pub fn to_hex(&self, p: &BigUint) -> String {
+        // Identity => "00"
+        // Coordinate(x, y) => x || y, each zero padded to the byte length of p
+        match self {
+            Point::Coordinate(x, y) => {
+                assert!(x < p, "{x} >= {p}");
+                assert!(y < p, "{y} >= {p}");
+
+                let width = byte_length(p);
+                encode_fixed_hex(x, width) + &encode_fixed_hex(y, width)
+            }
+            Point::Identity => String::from("00"),
+        }
+    }
+
+    pub fn from_hex(s: &str, p: &BigUint) -> Option<Point> {
+        if s == "00" {
+            return Some(Point::Identity);
+        }
+
+        // split_at below counts bytes, so anything other than ASCII is rejected before it
+        let width = byte_length(p);
+        if !s.is_ascii() || s.len() != 4 * width {
+            return None;
+        }
+        let (x, y) = s.split_at(2 * width);
+        let x = decode_fixed_hex(x, width)?;
+        let y = decode_fixed_hex(y, width)?;
+        if x < *p && y < *p {
+            Some(Point::Coordinate(x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Identity => 0x00
+        // Coordinate(x, y) => 0x01 || len(x) || x || len(y) || y
+        //
+        // lengths are 4 byte big-endian and coordinates are unpadded big-endian
+        match self {
+            Point::Coordinate(x, y) => {
+                let mut bytes = vec![0x01];
+                for coordinate in [x, y] {
+                    let coordinate = coordinate.to_bytes_be();
+                    bytes.extend_from_slice(&(coordinate.len() as u32).to_be_bytes());
+                    bytes.extend_from_slice(&coordinate);
+                }
+                bytes
+            }
+            Point::Identity => vec![0x00],
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Point> {
+        match bytes.split_first()? {
+            (0x00, []) => Some(Point::Identity),
+            (0x01, rest) => {
+                let (x, rest) = split_length_prefixed(rest)?;
+                let (y, rest) = split_length_prefixed(rest)?;
+                if !rest.is_empty() {
+                    return None;
+                }
+                Some(Point::Coordinate(
+                    BigUint::from_bytes_be(x),
+                    BigUint::from_bytes_be(y),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Point {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Identity => O (point at infinity)
+        // Coordinate(x, y) => (0x.., 0x..)
+        match self {
+            Point::Coordinate(x, y) => write!(f, "({x:#x}, {y:#x})"),
+            Point::Identity => write!(f, "O"),
+        }
+    }
+}
+
+fn split_length_prefixed(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+    if bytes.len() < 4 {
+        return None;
+    }
+    let (length, rest) = bytes.split_at(4);
+    let length = u32::from_be_bytes(length.try_into().ok()?) as usize;
+    if rest.len() < length {
+        return None;
+    }
+    Some(rest.split_at(length))
+}
+
+pub(crate) fn byte_length(n: &BigUint) -> usize {
+    n.bits().div_ceil(8) as usize
+}
+
+pub(crate) fn encode_fixed_hex(n: &BigUint, width: usize) -> String {
+    format!("{:0>1$}", n.to_str_radix(16), 2 * width)
+}
+
+pub(crate) fn decode_fixed_hex(s: &str, width: usize) -> Option<BigUint> {
+    if s.len() != 2 * width || !s.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    BigUint::parse_bytes(s.as_bytes(), 16)
+}
+
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable {
+    base: Point,
+    window: usize,
+    // rows[j][v] = v * 2^(window * j) * base
+    rows: Vec<Vec<Point>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EllipticCurve<F: FieldElement = BigUint> {
     // y^2 = x^3 + ax + b;
+    a: F,
+    b: F,
+    field: F::Field,
+}
+
+// the serialized form of EllipticCurve over BigUint; p - 2 is derived from p and never
+// serialized, and is recomputed on the way back in
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "EllipticCurve")]
+struct EllipticCurveParams {
+    #[serde(with = "serde_hex")]
     a: BigUint,
+    #[serde(with = "serde_hex")]
     b: BigUint,
+    #[serde(with = "serde_hex")]
     p: BigUint,
 }
 
-impl EllipticCurve {
-    pub fn new(a: BigUint, b: BigUint, p: BigUint) -> Self {
-        Self { a, b, p }
+#[cfg(feature = "serde")]
+impl From<EllipticCurveParams> for EllipticCurve {
+    fn from(params: EllipticCurveParams) -> Self {
+        EllipticCurve::new(params.a, params.b, params.p)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for EllipticCurve {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EllipticCurveParams {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            p: self.field.modulus().clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for EllipticCurve {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        EllipticCurveParams::deserialize(deserializer).map(EllipticCurve::from)
+    }
+}
+
+impl<F: FieldElement> EllipticCurve<F> {
+    pub fn with_field(a: F, b: F, field: F::Field) -> Self {
+        Self { a, b, field }
     }
-    pub fn add(&self, c: &Point, d: &Point) -> Point {
+
+    pub fn a(&self) -> &F {
+        &self.a
+    }
+
+    pub fn b(&self) -> &F {
+        &self.b
+    }
+
+    pub fn field(&self) -> &F::Field {
+        &self.field
+    }
+
+    pub fn add(&self, c: &Point<F>, d: &Point<F>) -> Point<F> {
         assert!(self.is_on_curve(c), "{:?} is not on curve", c);
         assert!(self.is_on_curve(d), "{:?} is not on curve", d);
-        assert_ne!(*c, *d, "Points should not be the same");
 
         match (c, d) {
             (Point::Identity, Point::Coordinate(x, y)) => Point::Coordinate(x.clone(), y.clone()),
             (Point::Coordinate(x, y), Point::Identity) => Point::Coordinate(x.clone(), y.clone()),
             (Point::Coordinate(x1, y1), Point::Coordinate(x2, y2)) => {
-                if FiniteField::add(y1, y2, &self.p) == BigUint::from(0u32) && x1 == x2 {
+                // x1 == x2 leaves the chord vertical, so there is no slope to divide by:
+                // either d = c and the tangent is used, or d = -c
+                if x1 == x2 {
+                    if y1 == y2 {
+                        return self.double(c);
+                    }
                     return Point::Identity;
                 }
                 // s = (y2 - y1) / (x2 - x1) mod p
                 // x3 = s^2 - x1 - x2 mod p
                 // y3 = s(x1 - x3) - y1 mod p
-                let delta_y = FiniteField::subtract(y2, y1, &self.p);
-                let delta_x = FiniteField::subtract(x2, x1, &self.p);
-                let s = FiniteField::divide(&delta_y, &delta_x, &self.p);
+                let s = self.add_slope(x1, y1, x2, y2);
                 self.compute_third_point(x1, y1, x2, &s)
             }
             _ => Point::Identity,
         }
     }
 
-    pub fn double(&self, c: &Point) -> Point {
+    pub fn double(&self, c: &Point<F>) -> Point<F> {
+        // 2 * Identity = Identity, without touching the field
+        if let Point::Identity = c {
+            return Point::Identity;
+        }
         assert!(self.is_on_curve(c), "{:?} is not on curve", c);
 
         if let Point::Coordinate(x, y) = c {
             // s = (3 * x^2 + a) / (2 * y) mod p
             // x0 = s^2 - 2 * x mod p
             // y0 = s(x - x0) - y mod p
-            if *y == BigUint::from(0u32) {
+            if *y == F::zero(&self.field) {
                 return Point::Identity
             }
-            let x_squared = x.modpow(&BigUint::from(2u32), &self.p);
-            let numerator = FiniteField::add(
-                &FiniteField::multiplication(&BigUint::from(3u32), &x_squared, &self.p),
-                &self.a,
-                &self.p,
-            );
-            let denominator = FiniteField::multiplication(&BigUint::from(2u32), y, &self.p);
-            let s = FiniteField::divide(&numerator, &denominator, &self.p);
+            let s = self.double_slope(x, y);
             self.compute_third_point(x, y, x, &s)
         } else {
             Point::Identity
         }
     }
 
-    pub fn scalar_multiplication(&self, a: &Point, d: &BigUint) -> Point {
+    pub fn add_owned(&self, c: Point<F>, d: Point<F>) -> Point<F> {
+        // same as add, but an input that is the result is moved out instead of cloned
+        assert!(self.is_on_curve(&c), "{:?} is not on curve", c);
+        assert!(self.is_on_curve(&d), "{:?} is not on curve", d);
+
+        match c {
+            Point::Identity => d,
+            c => self.add_unchecked(c, &d),
+        }
+    }
+
+    pub fn double_owned(&self, c: Point<F>) -> Point<F> {
+        assert!(self.is_on_curve(&c), "{:?} is not on curve", c);
+
+        self.double_unchecked(c)
+    }
+
+    fn add_unchecked(&self, c: Point<F>, d: &Point<F>) -> Point<F> {
+        self.add_unchecked_with(c, d, Self::divide)
+    }
+
+    fn double_unchecked(&self, c: Point<F>) -> Point<F> {
+        self.double_unchecked_with(c, Self::divide)
+    }
+
+    fn add_unchecked_with(
+        &self,
+        c: Point<F>,
+        d: &Point<F>,
+        divide: fn(&Self, &F, &F) -> F,
+    ) -> Point<F> {
+        // c + d, both already known to be on the curve, with the slope computed by divide
+        match (c, d) {
+            (Point::Identity, d) => d.clone(),
+            (c, Point::Identity) => c,
+            (Point::Coordinate(x1, y1), Point::Coordinate(x2, y2)) => {
+                if x1 == *x2 {
+                    if y1 == *y2 {
+                        return self.double_unchecked_with(Point::Coordinate(x1, y1), divide);
+                    }
+                    return Point::Identity;
+                }
+                let s = self.add_slope_with(&x1, &y1, x2, y2, divide);
+                self.compute_third_point(&x1, &y1, x2, &s)
+            }
+        }
+    }
+
+    fn double_unchecked_with(&self, c: Point<F>, divide: fn(&Self, &F, &F) -> F) -> Point<F> {
+        // 2 * c, for c already known to be on the curve, with the slope computed by divide
+        match c {
+            Point::Coordinate(x, y) if y != F::zero(&self.field) => {
+                let s = self.double_slope_with(&x, &y, divide);
+                self.compute_third_point(&x, &y, &x, &s)
+            }
+            _ => Point::Identity,
+        }
+    }
+
+    pub fn negate(&self, c: &Point<F>) -> Point<F> {
+        assert!(self.is_on_curve(c), "{:?} is not on curve", c);
+
+        // -(x, y) = (x, -y mod p)
+        match c {
+            Point::Coordinate(x, y) => Point::Coordinate(x.clone(), y.neg(&self.field)),
+            Point::Identity => Point::Identity,
+        }
+    }
+
+    pub fn subtract(&self, c: &Point<F>, d: &Point<F>) -> Point<F> {
+        // c - d = c + (-d)
+        self.add(c, &self.negate(d))
+    }
+
+    pub fn scalar_multiplication(&self, a: &Point<F>, d: &BigUint) -> Point<F> {
         // addition/doubling algorithm - B = d * A
         //
         // T = A
@@ -72,274 +391,2508 @@ impl EllipticCurve {
         //      T = 2 * T
         //      if bit i of d == 1
         //          T = T + A
+        //
+        // A is checked once up front; every T after that is a multiple of A, so the loop
+        // skips re-checking it and works on T by value. T can pass through Identity part way
+        // when d exceeds the order of A, and the next T + A is then simply A
+        if *d == BigUint::from(0u32) || *a == Point::Identity {
+            return Point::Identity;
+        }
+        assert!(self.is_on_curve(a), "{:?} is not on curve", a);
+
         let mut t = a.clone();
         for i in (0..d.bits() - 1).rev() {
-            t = self.double(&t);
+            t = self.double_unchecked(t);
             if d.bit(i) {
-                t = self.add(&t, a);
+                t = self.add_unchecked(t, a);
             }
         }
         t
     }
 
-    pub fn is_on_curve(&self, c: &Point) -> bool {
+    pub fn is_on_curve(&self, c: &Point<F>) -> bool {
         if let Point::Coordinate(x, y) = c {
+            // coordinates must be elements of the field, e.g. reduced mod p
+            if !x.belongs_to(&self.field) || !y.belongs_to(&self.field) {
+                return false;
+            }
+
             // y^2 = x^3 + a * x + b
-            let y_square = y.modpow(&BigUint::from(2u32), &self.p);
-            let x_cubed = x.modpow(&BigUint::from(3u32), &self.p);
-            let ax = FiniteField::multiplication(&self.a, x, &self.p);
-            y_square
-                == FiniteField::add(&x_cubed, &FiniteField::add(&ax, &self.b, &self.p), &self.p)
+            let y_square = y.mul(y, &self.field);
+            y_square == self.evaluate(x)
         } else {
             true
         }
     }
 
-    fn compute_third_point(&self, x1: &BigUint, y1: &BigUint, x2: &BigUint, s: &BigUint) -> Point {
-        let s_square = s.modpow(&BigUint::from(2u32), &self.p);
-        let x3 = FiniteField::subtract(
-            &FiniteField::subtract(&s_square, x1, &self.p),
-            &x2,
-            &self.p,
-        );
-        let y3 = FiniteField::subtract(
-            &FiniteField::multiplication(
-                &s,
-                &FiniteField::subtract(x1, &x3, &self.p),
-                &self.p,
-            ),
-            y1,
-            &self.p,
-        );
-        assert!(x3 < self.p, "{x3} >= {}", self.p);
-        assert!(y3 < self.p, "{y3} >= {}", self.p);
+    fn evaluate(&self, x: &F) -> F {
+        // x^3 + a * x + b mod p
+        let x_cubed = x.mul(x, &self.field).mul(x, &self.field);
+        let ax = self.a.mul(x, &self.field);
+        x_cubed.add(&ax.add(&self.b, &self.field), &self.field)
+    }
+
+    fn add_slope(&self, x1: &F, y1: &F, x2: &F, y2: &F) -> F {
+        self.add_slope_with(x1, y1, x2, y2, Self::divide)
+    }
+
+    fn double_slope(&self, x: &F, y: &F) -> F {
+        self.double_slope_with(x, y, Self::divide)
+    }
+
+    fn add_slope_with(&self, x1: &F, y1: &F, x2: &F, y2: &F, divide: fn(&Self, &F, &F) -> F) -> F {
+        // s = (y2 - y1) / (x2 - x1) mod p
+        let delta_y = y2.sub(y1, &self.field);
+        let delta_x = x2.sub(x1, &self.field);
+        divide(self, &delta_y, &delta_x)
+    }
+
+    fn double_slope_with(&self, x: &F, y: &F, divide: fn(&Self, &F, &F) -> F) -> F {
+        // s = (3 * x^2 + a) / (2 * y) mod p
+        let x_squared = x.mul(x, &self.field);
+        let numerator = x_squared
+            .add(&x_squared, &self.field)
+            .add(&x_squared, &self.field)
+            .add(&self.a, &self.field);
+        let denominator = y.add(y, &self.field);
+        divide(self, &numerator, &denominator)
+    }
+
+    fn divide(&self, c: &F, d: &F) -> F {
+        // c / d mod p
+        c.mul(&d.inverse(&self.field), &self.field)
+    }
+
+    fn compute_third_point(&self, x1: &F, y1: &F, x2: &F, s: &F) -> Point<F> {
+        // x3 = s^2 - x1 - x2 mod p
+        // y3 = s(x1 - x3) - y1 mod p
+        //
+        // over BigUint the field operations reduce their inputs, so any of x1, y1, x2 and s
+        // may be >= p
+        let s_square = s.mul(s, &self.field);
+        let x3 = s_square.sub(x1, &self.field).sub(x2, &self.field);
+        let y3 = s.mul(&x1.sub(&x3, &self.field), &self.field).sub(y1, &self.field);
 
         Point::Coordinate(x3, y3)
     }
 }
 
-pub struct FiniteField;
+impl EllipticCurve {
+    pub fn new(a: BigUint, b: BigUint, p: BigUint) -> Self {
+        Self::with_field(a, b, PrimeField::new(p))
+    }
 
-impl FiniteField {
-    fn add(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
-        // c + d = r mod p
+    pub fn from_signed(a: BigInt, b: BigInt, p: BigUint) -> Self {
+        // a and b may be negative, e.g. a = -3, and are reduced into [0, p)
+        let modulus = BigInt::from(p.clone());
+        let reduce = |c: BigInt| {
+            let mut r = c % &modulus;
+            if r.sign() == Sign::Minus {
+                r += &modulus;
+            }
+            r.to_biguint().expect("r is not negative")
+        };
+        Self::new(reduce(a), reduce(b), p)
+    }
 
-        assert!(c < p, "{c} >= {p}");
-        assert!(d < p, "{d} >= {p}");
+    pub fn modulus(&self) -> &BigUint {
+        self.field.modulus()
+    }
+    pub fn try_add(&self, c: &Point, d: &Point) -> Result<Point, EcError> {
+        self.check_point(c)?;
+        self.check_point(d)?;
+        Ok(self.add(c, d))
+    }
 
-        let r = c + d;
-        r.modpow(&BigUint::from(1u32), p)
+    pub fn try_double(&self, c: &Point) -> Result<Point, EcError> {
+        self.check_point(c)?;
+        Ok(self.double(c))
     }
 
-    fn multiplication(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
-        // c * d = r mod p
+    pub fn try_scalar_multiplication(&self, a: &Point, d: &BigUint) -> Result<Point, EcError> {
+        self.check_point(a)?;
+        Ok(self.scalar_multiplication(a, d))
+    }
 
-        assert!(c < p, "{c} >= {p}");
-        assert!(d < p, "{d} >= {p}");
+    fn check_point(&self, c: &Point) -> Result<(), EcError> {
+        if self.is_on_curve(c) {
+            Ok(())
+        } else {
+            Err(EcError::NotOnCurve(c.clone()))
+        }
+    }
 
-        let r = c * d;
-        r.modpow(&BigUint::from(1u32), p)
+    pub fn scalar_multiplication_mod_order(
+        &self,
+        a: &Point,
+        d: &BigUint,
+        order: &BigUint,
+    ) -> Point {
+        // d * A = (d mod n) * A when n * A = Identity
+        self.scalar_multiplication(a, &(d % order))
     }
 
-    fn inverse_addition(c: &BigUint, p: &BigUint) -> BigUint {
-        // -c mod p
+    pub fn scalar_multiplication_uniform(
+        &self,
+        a: &Point,
+        d: &BigUint,
+        order: &BigUint,
+    ) -> Point {
+        // double-and-always-add over every bit of the order, so the number of iterations
+        // does not depend on the bit length of d
+        //
+        // T = Identity
+        // for i in range(bits of n, 0)
+        //      T = 2 * T
+        //      U = T + A
+        //      T = U if bit i of d == 1 else T
+        //
+        // while T is still Identity the doubling and addition shortcut, so this evens out
+        // the loop length but not the cost of each iteration. The slopes are inverted with
+        // inverse_multiplication_ct rather than the cached p - 2 used by add and double.
+        self.double_and_always_add(a, &(d % order), order.bits())
+    }
 
-        assert!(c < p, "number: {} is bigger or equal than: {}", c, p);
+    #[cfg(feature = "std")]
+    pub fn scalar_multiplication_blinded<R: Rng + ?Sized>(
+        &self,
+        a: &Point,
+        d: &BigUint,
+        order: &BigUint,
+        rng: &mut R,
+    ) -> Point {
+        // d * A = (d + r * n) * A when n * A = Identity, for a fresh random 64 bit r
+        //
+        // d + r * n < 2^64 * n, so every call runs over the same number of bits whatever
+        // the value of r
+        let r = rng.gen_biguint(BLINDING_BITS);
+        let blinded = d % order + r * order;
+        self.double_and_always_add(a, &blinded, order.bits() + BLINDING_BITS)
+    }
 
-        p - c
+    fn double_and_always_add(&self, a: &Point, d: &BigUint, bits: u64) -> Point {
+        assert!(d.bits() <= bits, "{d} does not fit in {bits} bits");
+
+        assert!(self.is_on_curve(a), "{:?} is not on curve", a);
+
+        // A is checked once up front and every T is a multiple of it. Each slope is inverted
+        // with inverse_multiplication_ct, so every inversion runs the same sequence of
+        // operations whatever T is.
+        //
+        // every point is selected at the width of p, whatever its coordinates
+        let width = byte_length(self.modulus());
+        let mut t = Point::Identity;
+        for i in (0..bits).rev() {
+            t = self.double_unchecked_with(t, Self::divide_ct);
+            let u = self.add_unchecked_with(t.clone(), a, Self::divide_ct);
+            t = select(t, u, d.bit(i), width);
+        }
+        t
     }
 
-    fn subtract(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
-        // c - d mod p
+    fn divide_ct(&self, c: &BigUint, d: &BigUint) -> BigUint {
+        // c / d mod p, inverting with the fixed sequence of operations of
+        // inverse_multiplication_ct
+        let d_inverse = FiniteField::inverse_multiplication_ct(d, self.modulus());
+        FiniteField::multiplication(c, &d_inverse, self.modulus())
+    }
 
-        assert!(c < p, "{c} >= {p}");
-        assert!(d < p, "{d} >= {p}");
+    pub fn scalar_multiplication_wnaf(&self, a: &Point, d: &BigUint, w: usize) -> Point {
+        // width-w NAF: d = sum(k_i * 2^i) with every non-zero k_i odd, |k_i| < 2^(w-1)
+        // and at most one of any w consecutive digits non-zero
+        //
+        // table = [A, 3A, 5A, ..., (2^(w-1) - 1)A]
+        // T = Identity
+        // for i in range(len(k) - 1, 0)
+        //      T = 2 * T
+        //      if k_i > 0
+        //          T = T + table[(k_i - 1) / 2]
+        //      if k_i < 0
+        //          T = T - table[(-k_i - 1) / 2]
+        assert!((2..=16).contains(&w), "window should be between 2 and 16");
+
+        let a_doubled = self.double(a);
+        let mut table = vec![a.clone()];
+        for i in 1..1usize << (w - 2) {
+            table.push(self.add(&table[i - 1], &a_doubled));
+        }
 
-        let d_inverse = FiniteField::inverse_addition(d, p);
-        assert!(d_inverse < p.clone(), "{d_inverse} >= {p}");
+        let mut t = Point::Identity;
+        for &k in Self::wnaf(d, w).iter().rev() {
+            t = self.double(&t);
+            if k > 0 {
+                t = self.add(&t, &table[(k as usize - 1) / 2]);
+            } else if k < 0 {
+                t = self.subtract(&t, &table[((-k) as usize - 1) / 2]);
+            }
+        }
+        t
+    }
 
-        FiniteField::add(c, &d_inverse, p)
+    fn wnaf(d: &BigUint, w: usize) -> Vec<i32> {
+        // least significant digit first
+        let modulus = 1i32 << w;
+        let mut d = d.clone();
+        let mut digits = Vec::with_capacity(d.bits() as usize + 1);
+        while d != BigUint::from(0u32) {
+            let mut k = 0;
+            if d.bit(0) {
+                // k = d mods 2^w
+                k = (0..w).fold(0i32, |k, i| k | (d.bit(i as u64) as i32) << i);
+                if k >= modulus / 2 {
+                    k -= modulus;
+                }
+                if k > 0 {
+                    d -= k as u32;
+                } else {
+                    d += (-k) as u32;
+                }
+            }
+            digits.push(k);
+            d >>= 1;
+        }
+        digits
     }
 
-    // TODO: this function uses Fermat's Little Theorem and thus is only valid for primes(p)
-    // only for p as a prime
-    fn inverse_multiplication(c: &BigUint, p: &BigUint) -> BigUint {
-        // c^(-1) mod p = c^(p-2) mod p
+    pub fn multi_scalar_mul(&self, pairs: &[(Point, BigUint)]) -> Point {
+        // sum(d_i * A_i)
+        //
+        // with the rayon feature the pairs are split across threads and the partial sums
+        // are added together at the end
+        #[cfg(feature = "rayon")]
+        return self.multi_scalar_mul_parallel(pairs);
 
-        assert!(c < p, "{c} >= {p}");
+        #[cfg(not(feature = "rayon"))]
+        return self.multi_scalar_mul_sequential(pairs);
+    }
 
-        c.modpow(&(p - BigUint::from(2u32)), p)
+    fn multi_scalar_mul_sequential(&self, pairs: &[(Point, BigUint)]) -> Point {
+        let mut sum = PointAccumulator::new(self);
+        for (a, d) in pairs {
+            sum.push(&self.scalar_multiplication(a, d));
+        }
+        sum.finish()
     }
 
-    fn divide(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
-        assert!(c < p, "{c} >= {p}");
-        assert!(d < p, "{d} >= {p}");
+    #[cfg(feature = "rayon")]
+    fn multi_scalar_mul_parallel(&self, pairs: &[(Point, BigUint)]) -> Point {
+        let chunk_size = pairs.len().div_ceil(rayon::current_num_threads()).max(1);
+        pairs
+            .par_chunks(chunk_size)
+            .map(|chunk| self.multi_scalar_mul_sequential(chunk))
+            .reduce(|| Point::Identity, |c, d| self.add(&c, &d))
+    }
 
-        let d_inverse = FiniteField::inverse_multiplication(d, p);
-        assert!(d_inverse < p.clone(), "{d_inverse} >= {p}");
+    pub fn double_table(&self, base: &Point, bits: u64) -> Vec<Point> {
+        // [A, 2A, 4A, ..., 2^(bits - 1)A]
+        //
+        // Building the table costs the same doublings as one scalar_multiplication, so it
+        // only pays off when the same base is multiplied by several scalars, e.g. the
+        // generator. Later multiplications then only perform additions.
+        let mut table = Vec::with_capacity(bits as usize);
+        let mut t = base.clone();
+        for _ in 0..bits {
+            let doubled = self.double(&t);
+            table.push(t);
+            t = doubled;
+        }
+        table
+    }
 
-        FiniteField::multiplication(c, &d_inverse, p)
+    pub fn scalar_multiplication_with_table(&self, table: &[Point], d: &BigUint) -> Point {
+        // d * A = sum(2^i * A) for every bit i of d set
+        assert!(
+            d.bits() <= table.len() as u64,
+            "scalar has {} bits but the table only covers {}",
+            d.bits(),
+            table.len()
+        );
+
+        let mut t = Point::Identity;
+        for (i, doubling) in table.iter().enumerate() {
+            if d.bit(i as u64) {
+                t = self.add(&t, doubling);
+            }
+        }
+        t
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    pub fn precompute_fixed_base(&self, base: &Point, window: usize) -> FixedBaseTable {
+        // The order of any point is at most p + 1 + 2 * sqrt(p), so scalars reduced mod the
+        // group order have at most bits of p + 1 bits and are covered by the table.
+        assert!((1..=16).contains(&window), "window should be between 1 and 16");
+
+        let bits = self.modulus().bits() as usize + 1;
+        let mut rows = Vec::with_capacity(bits.div_ceil(window));
+        let mut row_base = base.clone();
+        for _ in 0..bits.div_ceil(window) {
+            let mut row = vec![Point::Identity];
+            for v in 1..1usize << window {
+                row.push(self.add(&row[v - 1], &row_base));
+            }
+            rows.push(row);
+            for _ in 0..window {
+                row_base = self.double(&row_base);
+            }
+        }
 
-    #[test]
-    fn test_add_one() {
-        let c = BigUint::from(4u32);
-        let d = BigUint::from(10u32);
-        let p = BigUint::from(11u32);
+        FixedBaseTable {
+            base: base.clone(),
+            window,
+            rows,
+        }
+    }
 
-        let r = FiniteField::add(&c, &d, &p);
+    pub fn scalar_multiplication_fixed(&self, table: &FixedBaseTable, d: &BigUint) -> Point {
+        // d = sum(d_j * 2^(window * j)) => d * A = sum(rows[j][d_j])
+        if d.bits() as usize > table.rows.len() * table.window {
+            return self.scalar_multiplication(&table.base, d);
+        }
+
+        let mut t = Point::Identity;
+        for (j, row) in table.rows.iter().enumerate() {
+            let mut digit = 0usize;
+            for i in (0..table.window).rev() {
+                digit = (digit << 1) | d.bit((j * table.window + i) as u64) as usize;
+            }
+            t = self.add(&t, &row[digit]);
+        }
+        t
+    }
+
+    pub fn point_order(&self, point: &Point, max: &BigUint) -> Option<BigUint> {
+        // smallest k > 0 such that k * P = Identity, trying at most max values of k
+        let mut t = point.clone();
+        let mut k = BigUint::from(1u32);
+        while k <= *max {
+            if t == Point::Identity {
+                return Some(k);
+            }
+            t = self.add(&t, point);
+            k += 1u32;
+        }
+        None
+    }
+
+    pub fn is_in_prime_subgroup(&self, point: &Point, order: &BigUint) -> bool {
+        // order * P = Identity, for a point P on the curve
+        self.is_on_curve(point) && self.scalar_multiplication(point, order) == Point::Identity
+    }
+
+    pub fn is_valid_order(&self, generator: &Point, order: &BigUint) -> bool {
+        // n is the order of G when n * G = Identity and (n / f) * G != Identity for every
+        // prime factor f of n
+        //
+        // n is factored by trial division up to 2^16; if what is left over is not prime the
+        // factorisation is unknown and n is rejected rather than trusted
+        if *order == BigUint::from(0u32) || !self.is_on_curve(generator) {
+            return false;
+        }
+        if self.scalar_multiplication(generator, order) != Point::Identity {
+            return false;
+        }
+        match prime_factors(order) {
+            Some(factors) => factors
+                .iter()
+                .all(|f| self.scalar_multiplication(generator, &(order / f)) != Point::Identity),
+            None => false,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn random_point<R: Rng + ?Sized>(&self, rng: &mut R) -> Point {
+        // pick x at random until x^3 + a * x + b is a quadratic residue, then pick one
+        // of its two square roots at random
+        loop {
+            let x = rng.gen_biguint_below(self.modulus());
+            let y_square = self.evaluate(&x);
+            if FiniteField::legendre_symbol(&y_square, self.modulus()) == -1 {
+                continue;
+            }
+            let y = FiniteField::sqrt(&y_square, self.modulus()).expect("y^2 is a quadratic residue");
+            let y = if rng.gen::<bool>() {
+                FiniteField::inverse_addition(&y, self.modulus())
+            } else {
+                y
+            };
+            return Point::Coordinate(x, y);
+        }
+    }
+
+    pub fn hash_to_point(&self, data: &[u8]) -> Point {
+        // try-and-increment
+        //
+        // h = H(data || counter), counter as 4 byte big-endian
+        // x = h mod p, retried with the next counter until x^3 + a * x + b is a quadratic
+        // residue; the lowest bit of h picks which of the two square roots is y
+        for counter in 0u32.. {
+            let mut hasher = Sha256::new();
+            hasher.update(data);
+            hasher.update(counter.to_be_bytes());
+            let h = hasher.finalize();
+
+            let x = BigUint::from_bytes_be(&h) % self.modulus();
+            let y_square = self.evaluate(&x);
+            if FiniteField::legendre_symbol(&y_square, self.modulus()) == -1 {
+                continue;
+            }
+            let y = FiniteField::sqrt(&y_square, self.modulus()).expect("y^2 is a quadratic residue");
+            let y = if y.bit(0) == (h[h.len() - 1] & 1 == 1) {
+                y
+            } else {
+                FiniteField::inverse_addition(&y, self.modulus())
+            };
+            return Point::Coordinate(x, y);
+        }
+        unreachable!("no point found for any counter")
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        // Identity followed by every affine point, ordered by x then y
+        //
+        // this walks every x in [0, p) and is only meant for small teaching curves
+        assert!(self.modulus().bits() <= 32, "p = {} is too large to enumerate", self.modulus());
+
+        let p = u64::try_from(self.modulus()).expect("p fits in 32 bits");
+        let affine = (0..p).flat_map(move |x| {
+            let x = BigUint::from(x);
+            let y_square = self.evaluate(&x);
+            let roots = match FiniteField::sqrt(&y_square, self.modulus()) {
+                Some(y) if y == BigUint::from(0u32) => vec![y],
+                Some(y) => {
+                    let y_negated = FiniteField::inverse_addition(&y, self.modulus());
+                    vec![y.clone().min(y_negated.clone()), y.max(y_negated)]
+                }
+                None => vec![],
+            };
+            roots.into_iter().map(move |y| Point::Coordinate(x.clone(), y))
+        });
+        core::iter::once(Point::Identity).chain(affine)
+    }
+
+    pub fn count_points(&self) -> BigUint {
+        // #E(F_p) = 1 + sum over x in [0, p) of (1 + legendre(x^3 + ax + b))
+        //
+        // like points, this walks every x and is only meant for small teaching curves
+        assert!(self.modulus().bits() <= 32, "p = {} is too large to count points", self.modulus());
+
+        let p = u64::try_from(self.modulus()).expect("p fits in 32 bits");
+        let affine: u64 = (0..p)
+            .map(|x| {
+                let y_square = self.evaluate(&BigUint::from(x));
+                (1 + FiniteField::legendre_symbol(&y_square, self.modulus())) as u64
+            })
+            .sum();
+        BigUint::from(affine + 1)
+    }
+}
+
+#[cfg(feature = "std")]
+const BLINDING_BITS: u64 = 64;
+
+fn prime_factors(n: &BigUint) -> Option<Vec<BigUint>> {
+    // the distinct prime factors of n, or None if trial division up to 2^16 leaves a
+    // composite cofactor
+    let mut factors = Vec::new();
+    let mut rest = n.clone();
+    for f in 2u32..1 << 16 {
+        let f = BigUint::from(f);
+        if &f * &f > rest {
+            break;
+        }
+        if (&rest % &f) == BigUint::from(0u32) {
+            while (&rest % &f) == BigUint::from(0u32) {
+                rest /= &f;
+            }
+            factors.push(f);
+        }
+    }
+
+    if rest == BigUint::from(1u32) {
+        Some(factors)
+    } else if FiniteField::is_probably_prime(&rest, FiniteField::DEFAULT_PRIMALITY_ROUNDS) {
+        factors.push(rest);
+        Some(factors)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "subtle")]
+fn select(a: Point, b: Point, choice: bool, width: usize) -> Point {
+    Point::conditional_select(&a, &b, choice, width)
+}
+
+#[cfg(not(feature = "subtle"))]
+fn select(a: Point, b: Point, choice: bool, _width: usize) -> Point {
+    if choice {
+        b
+    } else {
+        a
+    }
+}
+
+pub struct FiniteField;
+
+impl FiniteField {
+    pub const DEFAULT_PRIMALITY_ROUNDS: usize = 16;
+
+    fn add(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
+        // c + d = r mod p
+
+        assert!(c < p, "{c} >= {p}");
+        assert!(d < p, "{d} >= {p}");
+
+        let r = c + d;
+        r % p
+    }
+
+    fn multiplication(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
+        // c * d = r mod p
+
+        assert!(c < p, "{c} >= {p}");
+        assert!(d < p, "{d} >= {p}");
+
+        let r = c * d;
+        r % p
+    }
+
+    pub fn pow(base: &BigUint, exp: &BigUint, p: &BigUint) -> BigUint {
+        // base^exp = r mod p
+
+        assert!(base < p, "{base} >= {p}");
+
+        base.modpow(exp, p)
+    }
+
+    pub fn inverse_addition(c: &BigUint, p: &BigUint) -> BigUint {
+        // -c mod p
+
+        assert!(c < p, "number: {} is bigger or equal than: {}", c, p);
+
+        if *c == BigUint::from(0u32) {
+            return BigUint::from(0u32);
+        }
+        p - c
+    }
+
+    fn subtract(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
+        // c - d mod p
+
+        assert!(c < p, "{c} >= {p}");
+        assert!(d < p, "{d} >= {p}");
+
+        let d_inverse = FiniteField::inverse_addition(d, p);
+        assert!(d_inverse < p.clone(), "{d_inverse} >= {p}");
+
+        FiniteField::add(c, &d_inverse, p)
+    }
+
+    // TODO: this function uses Fermat's Little Theorem and thus is only valid for primes(p)
+    // only for p as a prime
+    pub fn inverse_multiplication(c: &BigUint, p: &BigUint) -> BigUint {
+        // c^(-1) mod p = c^(p-2) mod p
+
+        assert!(c < p, "{c} >= {p}");
+
+        c.modpow(&(p - BigUint::from(2u32)), p)
+    }
+
+    pub fn inverse_multiplication_ct(c: &BigUint, p: &BigUint) -> BigUint {
+        // c^(-1) mod p = c^(p-2) mod p, with a Montgomery ladder over the bits of p - 2
+        //
+        // every bit costs exactly one multiplication and one squaring, and the branch is on
+        // the bits of the public p - 2 only, so the sequence of operations does not depend
+        // on c. The BigUint arithmetic underneath is still variable-time in the magnitude of
+        // its operands, so this is a constant sequence of operations, not constant time.
+
+        assert!(c < p, "{c} >= {p}");
+
+        let exponent = p - BigUint::from(2u32);
+        let mut r0 = BigUint::from(1u32) % p;
+        let mut r1 = c.clone();
+        for i in (0..p.bits()).rev() {
+            if exponent.bit(i) {
+                r0 = FiniteField::multiplication(&r0, &r1, p);
+                r1 = FiniteField::multiplication(&r1, &r1, p);
+            } else {
+                r1 = FiniteField::multiplication(&r0, &r1, p);
+                r0 = FiniteField::multiplication(&r0, &r0, p);
+            }
+        }
+        r0
+    }
+
+    pub fn inverse_multiplication_with_exponent(
+        c: &BigUint,
+        p: &BigUint,
+        p_minus_two: &BigUint,
+    ) -> BigUint {
+        // c^(-1) mod p = c^(p-2) mod p, for callers that keep p - 2 around between inversions
+
+        assert!(c < p, "{c} >= {p}");
+        debug_assert!(p_minus_two + 2u32 == *p, "{p_minus_two} != {p} - 2");
+
+        c.modpow(p_minus_two, p)
+    }
+
+    fn divide(c: &BigUint, d: &BigUint, p: &BigUint) -> BigUint {
+        assert!(c < p, "{c} >= {p}");
+        assert!(d < p, "{d} >= {p}");
+
+        let d_inverse = FiniteField::inverse_multiplication(d, p);
+        assert!(d_inverse < p.clone(), "{d_inverse} >= {p}");
+
+        FiniteField::multiplication(c, &d_inverse, p)
+    }
+
+    pub fn sqrt(a: &BigUint, p: &BigUint) -> Option<BigUint> {
+        // r such that r^2 = a mod p, or None if a is a quadratic non-residue
+
+        assert!(a < p, "{a} >= {p}");
+
+        let zero = BigUint::from(0u32);
+        let one = BigUint::from(1u32);
+        let two = BigUint::from(2u32);
+
+        if *a == zero || *p == two {
+            return Some(a.clone());
+        }
+        if FiniteField::legendre_symbol(a, p) != 1 {
+            return None;
+        }
+        if p % 4u32 == BigUint::from(3u32) {
+            // r = a^((p + 1) / 4) mod p
+            return Some(FiniteField::pow(a, &((p + &one) / 4u32), p));
+        }
+
+        // Tonelli-Shanks
+        //
+        // p - 1 = q * 2^s with q odd
+        let mut q = p - &one;
+        let mut s = 0u64;
+        while !q.bit(0) {
+            q >>= 1;
+            s += 1;
+        }
+        // z is any quadratic non-residue
+        let mut z = two.clone();
+        while FiniteField::legendre_symbol(&z, p) != -1 {
+            z += 1u32;
+        }
+
+        let mut m = s;
+        let mut c = FiniteField::pow(&z, &q, p);
+        let mut t = FiniteField::pow(a, &q, p);
+        let mut r = FiniteField::pow(a, &((&q + &one) / &two), p);
+        while t != one {
+            // least 0 < i < m such that t^(2^i) = 1
+            let mut i = 0u64;
+            let mut t_squared = t.clone();
+            while t_squared != one {
+                t_squared = FiniteField::multiplication(&t_squared, &t_squared, p);
+                i += 1;
+            }
+            // b = c^(2^(m - i - 1))
+            let mut b = c.clone();
+            for _ in 0..m - i - 1 {
+                b = FiniteField::multiplication(&b, &b, p);
+            }
+            m = i;
+            c = FiniteField::multiplication(&b, &b, p);
+            t = FiniteField::multiplication(&t, &c, p);
+            r = FiniteField::multiplication(&r, &b, p);
+        }
+        Some(r)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn is_probably_prime(n: &BigUint, rounds: usize) -> bool {
+        // Miller-Rabin with `rounds` random witnesses, see is_probably_prime_with_rng
+        FiniteField::is_probably_prime_with_rng(n, rounds, &mut rand::thread_rng())
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn is_probably_prime(n: &BigUint, rounds: usize) -> bool {
+        // Miller-Rabin with the first `rounds` primes as witnesses, there being no randomness
+        // without std. This is deterministic: with 13 or more rounds it is exact for
+        // n < 3.3 * 10^24, but above that composites can be built to pass every fixed set of
+        // bases (Arnault), so it must not be trusted with adversarial n
+        let witnesses = (2u32..).filter(|a| (2..*a).all(|f| a % f != 0));
+        FiniteField::miller_rabin(n, witnesses.take(rounds).map(BigUint::from))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn is_probably_prime_with_rng<R: Rng + ?Sized>(
+        n: &BigUint,
+        rounds: usize,
+        rng: &mut R,
+    ) -> bool {
+        // Miller-Rabin with `rounds` witnesses drawn uniformly from [2, n - 2]: a composite n
+        // passes with probability at most 4^-rounds, however it was chosen
+        let two = BigUint::from(2u32);
+        let witnesses = (0..rounds).map(|_| rng.gen_biguint_range(&two, &(n - 1u32)));
+        FiniteField::miller_rabin(n, witnesses)
+    }
+
+    fn miller_rabin<I: Iterator<Item = BigUint>>(n: &BigUint, witnesses: I) -> bool {
+        // n - 1 = d * 2^s with d odd
+        // n is composite if for some witness a: a^d != 1 and a^(d * 2^r) != n - 1 for all r < s
+        //
+        // witnesses are only drawn once n is known to be odd and at least 5
+
+        let one = BigUint::from(1u32);
+        let two = BigUint::from(2u32);
+
+        if *n < two {
+            return false;
+        }
+        if *n == two || *n == BigUint::from(3u32) {
+            return true;
+        }
+        if !n.bit(0) {
+            return false;
+        }
+
+        let n_minus_one = n - &one;
+        let s = n_minus_one.trailing_zeros().expect("n - 1 is not zero");
+        let d = &n_minus_one >> s;
+
+        'witness: for a in witnesses {
+            let a = a % n;
+            if a == BigUint::from(0u32) {
+                continue;
+            }
+            let mut x = a.modpow(&d, n);
+            if x == one || x == n_minus_one {
+                continue;
+            }
+            for _ in 1..s {
+                x = x.modpow(&two, n);
+                if x == n_minus_one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    pub fn legendre_symbol(a: &BigUint, p: &BigUint) -> i8 {
+        // Euler's criterion: a^((p - 1) / 2) mod p
+        //
+        //  0 if a == 0 mod p
+        //  1 if a is a quadratic residue mod p
+        // -1 if a is a quadratic non-residue mod p
+
+        assert!(a < p, "{a} >= {p}");
+
+        let exponent = (p - BigUint::from(1u32)) / BigUint::from(2u32);
+        let r = FiniteField::pow(a, &exponent, p);
+
+        if r == BigUint::from(0u32) {
+            0
+        } else if r == BigUint::from(1u32) {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fp::Fp;
+    use alloc::rc::Rc;
+
+    #[test]
+    fn test_add_one() {
+        let c = BigUint::from(4u32);
+        let d = BigUint::from(10u32);
+        let p = BigUint::from(11u32);
+
+        let r = FiniteField::add(&c, &d, &p);
+
+        assert_eq!(r, BigUint::from(3u32));
+    }
+
+    #[test]
+    fn test_add_two() {
+        let c = BigUint::from(4u32);
+        let d = BigUint::from(10u32);
+        let p = BigUint::from(31u32);
+
+        let r = FiniteField::add(&c, &d, &p);
+
+        assert_eq!(r, BigUint::from(14u32));
+    }
+
+    #[test]
+    fn test_multiplication_one() {
+        let c = BigUint::from(4u32);
+        let d = BigUint::from(10u32);
+        let p = BigUint::from(11u32);
+
+        let r = FiniteField::multiplication(&c, &d, &p);
+
+        assert_eq!(r, BigUint::from(7u32));
+    }
+
+    #[test]
+    fn test_multiplication_two() {
+        let c = BigUint::from(4u32);
+        let d = BigUint::from(10u32);
+        let p = BigUint::from(51u32);
+
+        let r = FiniteField::multiplication(&c, &d, &p);
+
+        assert_eq!(r, BigUint::from(40u32));
+    }
+
+    #[test]
+    fn test_pow() {
+        let c = BigUint::from(3u32);
+        let p = BigUint::from(11u32);
+
+        let r = FiniteField::pow(&c, &BigUint::from(4u32), &p);
+
+        assert_eq!(r, BigUint::from(4u32));
+    }
+
+    #[test]
+    fn test_pow_fermat() {
+        let c = BigUint::from(2u32);
+        let p = BigUint::from(17u32);
+
+        let r = FiniteField::pow(&c, &(&p - BigUint::from(1u32)), &p);
+
+        assert_eq!(r, BigUint::from(1u32));
+    }
+
+    #[test]
+    fn test_pow_zero_exponent() {
+        let c = BigUint::from(9u32);
+        let p = BigUint::from(17u32);
+
+        let r = FiniteField::pow(&c, &BigUint::from(0u32), &p);
+
+        assert_eq!(r, BigUint::from(1u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_base_out_of_range() {
+        let c = BigUint::from(17u32);
+        let p = BigUint::from(17u32);
+
+        FiniteField::pow(&c, &BigUint::from(2u32), &p);
+    }
+
+    #[test]
+    fn test_reduction_matches_modpow() {
+        // reducing with % gives the same result as the former modpow(1, p)
+        let one = BigUint::from(1u32);
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+
+        let mut c = BigUint::from(3u32);
+        let mut d = &p - BigUint::from(5u32);
+        for _ in 0..200 {
+            assert_eq!(FiniteField::add(&c, &d, &p), (&c + &d).modpow(&one, &p));
+            assert_eq!(FiniteField::multiplication(&c, &d, &p), (&c * &d).modpow(&one, &p));
+            assert_eq!(
+                FiniteField::subtract(&c, &d, &p),
+                (&c + (&p - &d)).modpow(&one, &p)
+            );
+
+            c = (&c * &c + 7u32) % &p;
+            d = (&d * &c + 11u32) % &p;
+        }
+
+        let p = BigUint::from(17u32);
+        for c in 0u32..17 {
+            for d in 0u32..17 {
+                let (c, d) = (BigUint::from(c), BigUint::from(d));
+                assert_eq!(FiniteField::add(&c, &d, &p), (&c + &d).modpow(&one, &p));
+                assert_eq!(FiniteField::multiplication(&c, &d, &p), (&c * &d).modpow(&one, &p));
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_addition_one() {
+        let c = BigUint::from(4u32);
+        let p = BigUint::from(31u32);
+
+        let r = FiniteField::inverse_addition(&c, &p);
+
+        assert_eq!(r, BigUint::from(27u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_addition_two() {
+        let c = BigUint::from(32u32);
+        let p = BigUint::from(31u32);
+
+        FiniteField::inverse_addition(&c, &p);
+    }
+
+    #[test]
+    fn test_inverse_multiplication_ct() {
+        for p in [2u32, 3, 17, 31, 65537] {
+            let p = BigUint::from(p);
+            for c in (1u32..200).map(BigUint::from).filter(|c| *c < p) {
+                assert_eq!(
+                    FiniteField::inverse_multiplication_ct(&c, &p),
+                    FiniteField::inverse_multiplication(&c, &p),
+                    "{c}^-1 mod {p}"
+                );
+            }
+        }
+
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let mut c = BigUint::from(3u32);
+        for _ in 0..20 {
+            let c_inverse = FiniteField::inverse_multiplication_ct(&c, &p);
+            assert_eq!(c_inverse, FiniteField::inverse_multiplication(&c, &p));
+            assert_eq!(FiniteField::multiplication(&c, &c_inverse, &p), BigUint::from(1u32));
+            c = (&c * &c + 7u32) % &p;
+        }
+    }
+
+    #[test]
+    fn test_inverse_multiplication_with_exponent() {
+        let p = BigUint::from(31u32);
+        let p_minus_two = BigUint::from(29u32);
+
+        for c in 1u32..31 {
+            let c = BigUint::from(c);
+            assert_eq!(
+                FiniteField::inverse_multiplication_with_exponent(&c, &p, &p_minus_two),
+                FiniteField::inverse_multiplication(&c, &p)
+            );
+        }
+
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p.clone());
+        let c = BigUint::from(0xDEADBEEFu32);
+        let d = &p - BigUint::from(5u32);
+        assert_eq!(ec.divide(&c, &d), FiniteField::divide(&c, &d, &p));
+        assert_eq!(ec.field.p_minus_two, &p - BigUint::from(2u32));
+    }
+
+    #[test]
+    fn test_inverse_addition_zero() {
+        let p = BigUint::from(31u32);
+
+        assert_eq!(FiniteField::inverse_addition(&BigUint::from(0u32), &p), BigUint::from(0u32));
+        assert_eq!(FiniteField::inverse_addition(&BigUint::from(1u32), &p), BigUint::from(30u32));
+        assert_eq!(FiniteField::inverse_addition(&BigUint::from(30u32), &p), BigUint::from(1u32));
+    }
+
+    #[test]
+    fn test_inverse_addition_identity() {
+        let c = BigUint::from(4u32);
+        let p = BigUint::from(31u32);
+
+        let c_inverse = FiniteField::inverse_addition(&c, &p);
+        let r = FiniteField::add(&c, &c_inverse, &p);
+
+        assert_eq!(r, BigUint::from(0u32));
+    }
+
+    #[test]
+    fn test_subtract_zero() {
+        let c = BigUint::from(4u32);
+        let p = BigUint::from(31u32);
+
+        assert_eq!(FiniteField::subtract(&c, &BigUint::from(0u32), &p), c)
+    }
+
+    #[test]
+    fn test_subtract() {
+        let c = BigUint::from(4u32);
+        let p = BigUint::from(31u32);
+
+        assert_eq!(FiniteField::subtract(&c, &c, &p), BigUint::from(0u32))
+    }
+
+    #[test]
+    fn test_inverse_multiplication_identity() {
+        let c = BigUint::from(4u32);
+        let p = BigUint::from(17u32);
+
+        let c_inverse = FiniteField::inverse_multiplication(&c, &p);
+        let r = FiniteField::multiplication(&c, &c_inverse, &p);
+
+        assert_eq!(r, BigUint::from(1u32));
+    }
+
+    #[test]
+    fn test_divide() {
+        let c = BigUint::from(4u32);
+        let p = BigUint::from(11u32);
+
+        assert_eq!(FiniteField::divide(&c, &c, &p), BigUint::from(1u32));
+    }
+
+    #[test]
+    fn test_legendre_symbol_residues() {
+        let p = BigUint::from(17u32);
+
+        for a in [1u32, 2, 4, 8, 9, 13, 15, 16] {
+            assert_eq!(FiniteField::legendre_symbol(&BigUint::from(a), &p), 1, "{a}");
+        }
+    }
+
+    #[test]
+    fn test_legendre_symbol_non_residues() {
+        let p = BigUint::from(17u32);
+
+        for a in [3u32, 5, 6, 7, 10, 11, 12, 14] {
+            assert_eq!(FiniteField::legendre_symbol(&BigUint::from(a), &p), -1, "{a}");
+        }
+    }
+
+    #[test]
+    fn test_legendre_symbol_zero() {
+        let p = BigUint::from(17u32);
+
+        assert_eq!(FiniteField::legendre_symbol(&BigUint::from(0u32), &p), 0);
+    }
+
+    #[test]
+    fn test_sqrt_tonelli_shanks() {
+        // 17 = 1 mod 4
+        let p = BigUint::from(17u32);
+
+        for a in 0u32..17 {
+            let a = BigUint::from(a);
+            match FiniteField::sqrt(&a, &p) {
+                Some(r) => assert_eq!(FiniteField::multiplication(&r, &r, &p), a),
+                None => assert_eq!(FiniteField::legendre_symbol(&a, &p), -1),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_three_mod_four() {
+        // 23 = 3 mod 4
+        let p = BigUint::from(23u32);
+
+        for a in 0u32..23 {
+            let a = BigUint::from(a);
+            match FiniteField::sqrt(&a, &p) {
+                Some(r) => assert_eq!(FiniteField::multiplication(&r, &r, &p), a),
+                None => assert_eq!(FiniteField::legendre_symbol(&a, &p), -1),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_non_residue() {
+        let p = BigUint::from(17u32);
+
+        assert_eq!(FiniteField::sqrt(&BigUint::from(3u32), &p), None);
+        assert_eq!(FiniteField::sqrt(&BigUint::from(0u32), &p), Some(BigUint::from(0u32)));
+    }
+
+    #[test]
+    fn test_ec_from_signed() {
+        // y^2 = x^3 - 3x - 12 mod 17 = x^3 + 14x + 5 mod 17
+        let ec = EllipticCurve::from_signed(
+            BigInt::from(-3),
+            BigInt::from(-12),
+            BigUint::from(17u32),
+        );
+        assert_eq!(
+            ec,
+            EllipticCurve::new(BigUint::from(14u32), BigUint::from(5u32), BigUint::from(17u32))
+        );
+
+        // 8^2 = 5^3 - 3 * 5 - 12 = 13 mod 17
+        assert!(ec.is_on_curve(&Point::Coordinate(BigUint::from(5u32), BigUint::from(8u32))));
+
+        // positive values at or above p are reduced as well
+        let ec = EllipticCurve::from_signed(
+            BigInt::from(-37),
+            BigInt::from(39),
+            BigUint::from(17u32),
+        );
+        assert_eq!(*ec.a(), BigUint::from(14u32));
+        assert_eq!(*ec.b(), BigUint::from(5u32));
+    }
+
+    #[test]
+    fn test_ec_parameters() {
+        // y^2 = x^3 + 2x + 3 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+
+        assert_eq!(*ec.a(), BigUint::from(2u32));
+        assert_eq!(*ec.b(), BigUint::from(3u32));
+        assert_eq!(*ec.modulus(), BigUint::from(17u32));
+    }
+
+    #[test]
+    fn test_ec_equality() {
+        let secp256k1 = || {
+            let p = BigUint::parse_bytes(
+                b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+                16
+            ).expect("Could not convert p");
+            EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p)
+        };
+
+        let ec1 = secp256k1();
+        let ec2 = secp256k1();
+        assert_eq!(ec1, ec2);
+        assert_eq!(ec1.clone(), ec2);
+
+        let ec3 = EllipticCurve::new(
+            BigUint::from(0u32),
+            BigUint::from(5u32),
+            ec1.modulus().clone(),
+        );
+        assert_ne!(ec1, ec3);
+    }
+
+    #[test]
+    fn test_ec_compute_third_point_large_slope() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let p = BigUint::from(17u32);
+
+        // (6, 3) + (5, 1) = (10, 6) with s = (1 - 3) / (5 - 6) = 2 mod 17
+        let (x1, y1, x2) = (BigUint::from(6u32), BigUint::from(3u32), BigUint::from(5u32));
+        let expected = Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32));
+        for k in [0u32, 1, 2, 1000] {
+            let s = BigUint::from(2u32) + &p * k;
+            assert_eq!(ec.compute_third_point(&x1, &y1, &x2, &s), expected, "s = {s}");
+        }
+
+        // intermediate values at or above p are reduced too
+        let x1_unreduced = &x1 + &p;
+        let y1_unreduced = &y1 + &p * 3u32;
+        let x2_unreduced = &x2 + &p * 2u32;
+        assert_eq!(
+            ec.compute_third_point(&x1_unreduced, &y1_unreduced, &x2_unreduced, &(&p * 5u32 + 2u32)),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_is_probably_prime() {
+        let rounds = FiniteField::DEFAULT_PRIMALITY_ROUNDS;
+
+        for n in [2u32, 3, 5, 17, 19, 31, 7919] {
+            assert!(FiniteField::is_probably_prime(&BigUint::from(n), rounds), "{n}");
+        }
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        assert!(FiniteField::is_probably_prime(&p, rounds));
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+        assert!(FiniteField::is_probably_prime(&n, rounds));
+    }
+
+    #[test]
+    fn test_is_probably_prime_composites() {
+        let rounds = FiniteField::DEFAULT_PRIMALITY_ROUNDS;
+
+        for n in [0u32, 1, 4, 9, 15, 21, 51, 7917] {
+            assert!(!FiniteField::is_probably_prime(&BigUint::from(n), rounds), "{n}");
+        }
+        // p * n for the secp256k1 p and n
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+        assert!(!FiniteField::is_probably_prime(&(p * n), rounds));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_probably_prime_strong_pseudoprime() {
+        // 3317044064679887385961981 = 1287836182261 * 2575672364521 is a strong pseudoprime to
+        // each of the first 13 primes, and is only rejected here because the witnesses are
+        // random
+        let n = BigUint::parse_bytes(
+            b"3317044064679887385961981",
+            10
+        ).expect("Could not convert n");
+
+        assert!(!FiniteField::is_probably_prime(&n, 13));
+        assert!(!FiniteField::is_probably_prime_with_rng(&n, 13, &mut rand::thread_rng()));
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn test_is_probably_prime_strong_pseudoprime() {
+        // 3317044064679887385961981 = 1287836182261 * 2575672364521 is a strong pseudoprime to
+        // each of the first 13 primes, so the fixed witnesses accept it at 13 rounds
+        let n = BigUint::parse_bytes(
+            b"3317044064679887385961981",
+            10
+        ).expect("Could not convert n");
+
+        assert!(FiniteField::is_probably_prime(&n, 13));
+        assert!(!FiniteField::is_probably_prime(&n, 14));
+    }
+
+    #[test]
+    fn test_is_probably_prime_carmichael() {
+        // Carmichael numbers pass the Fermat test for every coprime base
+        let rounds = FiniteField::DEFAULT_PRIMALITY_ROUNDS;
+
+        for n in [561u32, 1105, 1729, 2465, 2821, 6601, 8911] {
+            let n = BigUint::from(n);
+            assert_eq!(BigUint::from(2u32).modpow(&(&n - 1u32), &n), BigUint::from(1u32));
+            assert!(!FiniteField::is_probably_prime(&n, rounds), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_ec_point_addition() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // (6, 3) + (5, 1) = (10, 6)
+        let p1 = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let p2 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let p3 = Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32));
+
+        let result = ec.add(&p1, &p2);
+        assert_eq!(result, p3);
+    }
+
+    #[test]
+    fn test_ec_is_on_curve_out_of_range() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // (5, 18) and (22, 1) are (5, 1) with a coordinate shifted by p, but not reduced
+        assert!(ec.is_on_curve(&Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32))));
+        assert!(!ec.is_on_curve(&Point::Coordinate(BigUint::from(5u32), BigUint::from(18u32))));
+        assert!(!ec.is_on_curve(&Point::Coordinate(BigUint::from(22u32), BigUint::from(1u32))));
+    }
+
+    #[test]
+    fn test_ec_try_add() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let p1 = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let p2 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(ec.try_add(&p1, &p2), Ok(ec.add(&p1, &p2)));
+        assert_eq!(ec.try_add(&p2, &p2), Ok(ec.double(&p2)));
+
+        // (6, 4) does not satisfy the curve equation
+        let off_curve = Point::Coordinate(BigUint::from(6u32), BigUint::from(4u32));
+        assert_eq!(
+            ec.try_add(&p1, &off_curve),
+            Err(EcError::NotOnCurve(off_curve.clone()))
+        );
+        assert_eq!(
+            ec.try_add(&off_curve, &p1),
+            Err(EcError::NotOnCurve(off_curve))
+        );
+
+        // (22, 1) = (5, 1) mod 17, but coordinates must be reduced
+        let out_of_range = Point::Coordinate(BigUint::from(22u32), BigUint::from(1u32));
+        assert_eq!(
+            ec.try_add(&p1, &out_of_range),
+            Err(EcError::NotOnCurve(out_of_range))
+        );
+    }
+
+    #[test]
+    fn test_ec_try_double() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let p1 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(ec.try_double(&p1), Ok(ec.double(&p1)));
+        assert_eq!(ec.try_double(&Point::Identity), Ok(Point::Identity));
+
+        let off_curve = Point::Coordinate(BigUint::from(5u32), BigUint::from(2u32));
+        assert_eq!(
+            ec.try_double(&off_curve),
+            Err(EcError::NotOnCurve(off_curve))
+        );
+
+        let out_of_range = Point::Coordinate(BigUint::from(5u32), BigUint::from(18u32));
+        assert_eq!(
+            ec.try_double(&out_of_range),
+            Err(EcError::NotOnCurve(out_of_range))
+        );
+    }
+
+    #[test]
+    fn test_ec_try_scalar_multiplication() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let d = BigUint::from(10u32);
+        assert_eq!(ec.try_scalar_multiplication(&a, &d), Ok(ec.scalar_multiplication(&a, &d)));
+
+        let off_curve = Point::Coordinate(BigUint::from(5u32), BigUint::from(2u32));
+        let result = ec.try_scalar_multiplication(&off_curve, &d);
+        assert_eq!(result, Err(EcError::NotOnCurve(off_curve)));
+        assert_eq!(
+            format!("{}", result.unwrap_err()),
+            "(0x5, 0x2) is not on curve"
+        );
+    }
+
+    #[test]
+    fn test_ec_point_doubling() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // (5, 1) + (5, 1) = 2 * (5, 1) = (6, 3)
+        let p1 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let pr = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let result = ec.double(&p1);
+        assert_eq!(result, pr);
+    }
+
+    #[test]
+    fn test_ec_point_doubling_identity() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // Point::Identity + Point::Identity = 2 * Point::Identity = Point::Identity
+        let p1 = Point::Identity;
+        let pr = Point::Identity;
+
+        let result = ec.double(&p1);
+        assert_eq!(result, pr);
+    }
+
+    #[test]
+    fn test_ec_point_negate() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // -(5, 1) = (5, 16)
+        let p1 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let pr = Point::Coordinate(BigUint::from(5u32), BigUint::from(16u32));
+        assert_eq!(ec.negate(&p1), pr);
+        assert_eq!(ec.add(&p1, &pr), Point::Identity);
+
+        assert_eq!(ec.negate(&Point::Identity), Point::Identity);
+    }
+
+    #[test]
+    fn test_ec_point_subtract() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // (10, 6) - (5, 1) = (6, 3)
+        let p1 = Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32));
+        let p2 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let p3 = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        assert_eq!(ec.subtract(&p1, &p2), p3);
+
+        assert_eq!(ec.subtract(&p1, &p1), Point::Identity);
+        assert_eq!(ec.subtract(&p1, &Point::Identity), p1);
+        // (5, 1) - (5, 16) = 2 * (5, 1)
+        assert_eq!(ec.subtract(&p2, &ec.negate(&p2)), p3);
+    }
+
+    #[test]
+    fn test_ec_scalar_multiplication() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        // 2 * (5, 1) = (6, 3)
+        let pr = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let result = ec.scalar_multiplication(&a, &BigUint::from(2u32));
+        assert_eq!(result, pr);
+
+        // 10 * (5, 1) = (7, 11)
+        let pr = Point::Coordinate(BigUint::from(7u32), BigUint::from(11u32));
+        let result = ec.scalar_multiplication(&a, &BigUint::from(10u32));
+        assert_eq!(result, pr);
+
+        // 16 * (5, 1) = (10, 11)
+        let pr = Point::Coordinate(BigUint::from(10u32), BigUint::from(11u32));
+        let result = ec.scalar_multiplication(&a, &BigUint::from(16u32));
+        assert_eq!(result, pr);
+
+        // 17 * (5, 1) = (6, 14)
+        let pr = Point::Coordinate(BigUint::from(6u32), BigUint::from(14u32));
+        let result = ec.scalar_multiplication(&a, &BigUint::from(17u32));
+        assert_eq!(result, pr);
+
+        // 18 * (5, 1) = (5, 16)
+        let pr = Point::Coordinate(BigUint::from(5u32), BigUint::from(16u32));
+        let result = ec.scalar_multiplication(&a, &BigUint::from(18u32));
+        assert_eq!(result, pr);
+
+        // 19 * (5, 1) = Point::Identity
+        let pr = Point::Identity;
+        let result = ec.scalar_multiplication(&a, &BigUint::from(19u32));
+        assert_eq!(result, pr);
+    }
+
+    #[test]
+    fn test_ec_over_fp() {
+        // y^2 = x^3 + 2x + 2 mod 17, with coordinates in Fp
+        let p = Rc::new(BigUint::from(17u32));
+        let fp = |c: u32| Fp::new(BigUint::from(c), Rc::clone(&p));
+        let ec = EllipticCurve::with_field(fp(2), fp(2), Rc::clone(&p));
+        let point = |x: u32, y: u32| Point::Coordinate(fp(x), fp(y));
+
+        // (6, 3) + (5, 1) = (10, 6)
+        assert_eq!(ec.add(&point(6, 3), &point(5, 1)), point(10, 6));
+        // 2 * (5, 1) = (6, 3)
+        assert_eq!(ec.double(&point(5, 1)), point(6, 3));
+        assert_eq!(ec.double(&Point::Identity), Point::Identity);
+        // -(5, 1) = (5, 16)
+        assert_eq!(ec.negate(&point(5, 1)), point(5, 16));
+        assert_eq!(ec.add(&point(5, 1), &point(5, 16)), Point::Identity);
+        // (10, 6) - (5, 1) = (6, 3)
+        assert_eq!(ec.subtract(&point(10, 6), &point(5, 1)), point(6, 3));
+        assert!(!ec.is_on_curve(&point(5, 2)));
+
+        // 10 * (5, 1) = (7, 11), 16 * (5, 1) = (10, 11), 17 * (5, 1) = (6, 14),
+        // 18 * (5, 1) = (5, 16) and 19 * (5, 1) = Identity
+        let a = point(5, 1);
+        for (d, expected) in [
+            (10u32, point(7, 11)),
+            (16, point(10, 11)),
+            (17, point(6, 14)),
+            (18, point(5, 16)),
+            (19, Point::Identity),
+        ] {
+            assert_eq!(ec.scalar_multiplication(&a, &BigUint::from(d)), expected, "d = {d}");
+        }
+
+        // every multiple agrees with the same curve over BigUint
+        let ec_biguint = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a_biguint = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        for d in 1u32..=19 {
+            let expected = match ec_biguint.scalar_multiplication(&a_biguint, &BigUint::from(d)) {
+                Point::Coordinate(x, y) => Point::Coordinate(
+                    Fp::new(x, Rc::clone(&p)),
+                    Fp::new(y, Rc::clone(&p)),
+                ),
+                Point::Identity => Point::Identity,
+            };
+            assert_eq!(ec.scalar_multiplication(&a, &BigUint::from(d)), expected, "d = {d}");
+        }
+    }
+
+    #[test]
+    fn test_ec_point_order() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let max = BigUint::from(100u32);
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(ec.point_order(&a, &max), Some(BigUint::from(19u32)));
+
+        // (0, 6) is also a generator of the group of order 19
+        let b = Point::Coordinate(BigUint::from(0u32), BigUint::from(6u32));
+        assert_eq!(ec.point_order(&b, &max), Some(BigUint::from(19u32)));
+
+        assert_eq!(ec.point_order(&Point::Identity, &max), Some(BigUint::from(1u32)));
+    }
+
+    #[test]
+    fn test_ec_point_order_bound() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(ec.point_order(&a, &BigUint::from(18u32)), None);
+        assert_eq!(ec.point_order(&a, &BigUint::from(19u32)), Some(BigUint::from(19u32)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ec_random_point() {
+        let mut rng = rand::thread_rng();
+
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        for _ in 0..200 {
+            let point = ec.random_point(&mut rng);
+            assert!(ec.is_on_curve(&point), "{:?} is not on curve", point);
+        }
+
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p);
+        for _ in 0..20 {
+            let point = ec.random_point(&mut rng);
+            assert!(ec.is_on_curve(&point), "{:?} is not on curve", point);
+        }
+    }
+
+    #[test]
+    fn test_ec_hash_to_point() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        for data in [&b""[..], b"a", b"b", b"hello world"] {
+            let point = ec.hash_to_point(data);
+            assert!(ec.is_on_curve(&point), "{:?} is not on curve", point);
+            assert_eq!(ec.hash_to_point(data), point);
+        }
+    }
+
+    #[test]
+    fn test_ec_hash_to_point_secp256k1() {
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p);
+
+        let points: Vec<Point> = [&b""[..], b"a", b"b", b"hello world"]
+            .iter()
+            .map(|data| ec.hash_to_point(data))
+            .collect();
+        for (i, point) in points.iter().enumerate() {
+            assert!(ec.is_on_curve(point), "{:?} is not on curve", point);
+            assert!(!points[i + 1..].contains(point), "{:?} is repeated", point);
+        }
+        assert_eq!(ec.hash_to_point(b"hello world"), points[3]);
+    }
+
+    #[test]
+    fn test_ec_scalar_multiplication_identity() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        for d in [0u32, 1, 5, 19, 1000] {
+            let d = BigUint::from(d);
+            assert_eq!(ec.scalar_multiplication(&Point::Identity, &d), Point::Identity);
+        }
+
+        // 39 = 0b100111: T runs through 1, 2, 4, 8, 9, 18, 19 = Identity, then 38 = Identity
+        // and 39 = 1 times A
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(ec.scalar_multiplication(&a, &BigUint::from(39u32)), a);
+        assert_eq!(ec.scalar_multiplication(&a, &BigUint::from(38u32)), Point::Identity);
+        assert_eq!(ec.scalar_multiplication(&a, &BigUint::from(40u32)), ec.double(&a));
+    }
+
+    #[test]
+    fn test_ec_scalar_multiplication_uniform() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let order = BigUint::from(19u32);
+
+        for d in 0u32..40 {
+            let d = BigUint::from(d);
+            assert_eq!(
+                ec.scalar_multiplication_uniform(&a, &d, &order),
+                ec.scalar_multiplication_mod_order(&a, &d, &order),
+                "{d} * A"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ec_divide_ct() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        for c in 0u32..17 {
+            for d in 1u32..17 {
+                let (c, d) = (BigUint::from(c), BigUint::from(d));
+                assert_eq!(ec.divide_ct(&c, &d), ec.divide(&c, &d), "{c} / {d}");
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ec_scalar_multiplication_blinded() {
+        let mut rng = rand::thread_rng();
+
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let order = BigUint::from(19u32);
+
+        for d in 0u32..40 {
+            let d = BigUint::from(d);
+            let expected = ec.scalar_multiplication_mod_order(&a, &d, &order);
+            for _ in 0..10 {
+                assert_eq!(
+                    ec.scalar_multiplication_blinded(&a, &d, &order, &mut rng),
+                    expected,
+                    "{d} * A"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ec_scalar_multiplication_uniform_secp256k1() {
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+        let gx = BigUint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16
+        ).expect("Could not convert gx");
+        let gy = BigUint::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16
+        ).expect("Could not convert gy");
+        let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p);
+        let g = Point::Coordinate(gx, gy);
+
+        // scalars from a single bit up to the full width of n
+        let scalars = [
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+            BigUint::from(0xDEADBEEFu32),
+            &n - BigUint::from(1u32),
+        ];
+        for d in scalars {
+            assert_eq!(
+                ec.scalar_multiplication_uniform(&g, &d, &n),
+                ec.scalar_multiplication(&g, &d)
+            );
+        }
+        assert_eq!(ec.scalar_multiplication_uniform(&g, &n, &n), Point::Identity);
+    }
+
+    #[test]
+    fn test_ec_add_double_owned() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let points: Vec<Point> = ec.points().collect();
+        for c in &points {
+            assert_eq!(ec.double_owned(c.clone()), ec.double(c));
+            for d in points.iter().filter(|d| *d != c) {
+                assert_eq!(ec.add_owned(c.clone(), d.clone()), ec.add(c, d));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ec_negate_self_inverse_point() {
+        // y^2 = x^3 + 2x + 3 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+
+        // y == 0, so -P = (16, 0) and not (16, p)
+        let c = Point::Coordinate(BigUint::from(16u32), BigUint::from(0u32));
+        assert_eq!(ec.negate(&c), c);
+        assert_eq!(ec.negate(&Point::Identity), Point::Identity);
+
+        // P has order 2
+        assert_eq!(ec.double(&c), Point::Identity);
+        assert_eq!(ec.add(&c, &c), Point::Identity);
+        assert_eq!(ec.add(&c, &ec.negate(&c)), Point::Identity);
+        assert_eq!(ec.subtract(&c, &c), Point::Identity);
+        assert_eq!(ec.scalar_multiplication(&c, &BigUint::from(3u32)), c);
+    }
+
+    #[test]
+    fn test_ec_add_inverse_in_scalar_multiplication() {
+        // the last addition of n * G is (n - 1) * G + G = -G + G, which add resolves from
+        // the x-coordinates alone
+
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let n = BigUint::from(19u32);
+
+        let t = ec.scalar_multiplication(&g, &(&n - BigUint::from(1u32)));
+        assert_eq!(t, ec.negate(&g));
+        assert_eq!(ec.add(&t, &g), Point::Identity);
+        assert_eq!(ec.scalar_multiplication(&g, &n), Point::Identity);
+
+        let (ec, g, n) = curves::secp256k1();
+        let t = ec.scalar_multiplication(&g, &(&n - BigUint::from(1u32)));
+        assert_eq!(t, ec.negate(&g));
+        assert_eq!(ec.add(&t, &g), Point::Identity);
+        assert_eq!(ec.scalar_multiplication(&g, &n), Point::Identity);
+    }
+
+    #[test]
+    fn test_ec_add_owned_same_points() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let c = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        assert_eq!(ec.add_owned(c.clone(), c.clone()), ec.double(&c));
+    }
+
+    #[test]
+    fn test_ec_add_equal_value_points() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // points compare by value: a clone and a freshly built point both double
+        for c in ec.points() {
+            assert_eq!(ec.add(&c.clone(), &c), ec.double(&c), "{:?}", c);
+        }
+        let c = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let d = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert!(!core::ptr::eq(&c, &d));
+        assert_eq!(ec.add(&c, &d), ec.double(&c));
+    }
+
+    #[test]
+    fn test_ec_add_same_x() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let c = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        // x1 == x2 and y1 == y2: the same point, doubled
+        assert_eq!(
+            ec.add(&c, &c),
+            Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32))
+        );
+
+        // x1 == x2 and y1 != y2: d = -c
+        let d = Point::Coordinate(BigUint::from(5u32), BigUint::from(16u32));
+        assert_eq!(ec.add(&c, &d), Point::Identity);
+        assert_eq!(ec.add(&d, &c), Point::Identity);
+
+        // x1 != x2: the chord through both points
+        let d = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        assert_eq!(
+            ec.add(&c, &d),
+            Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32))
+        );
+    }
+
+    #[test]
+    fn test_ec_clone() {
+        let (ec, g, n) = curves::secp256k1();
+        let ec_clone = ec.clone();
+        assert_eq!(ec_clone, ec);
+
+        let private_key = BigUint::from(0xDEADBEEFu32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        assert_eq!(ec_clone.scalar_multiplication(&g, &private_key), public_key);
+
+        // a signature made over one copy verifies over the other
+        let message = b"Bob -> 1 BTC -> Alice";
+        let k = BigUint::from(12345u32);
+        let signature = schnorr::sign(&ec, &g, &n, &private_key, message, &k);
+        assert_eq!(schnorr::sign(&ec_clone, &g, &n, &private_key, message, &k), signature);
+        assert!(schnorr::verify(&ec_clone, &g, &n, &public_key, message, &signature));
+    }
+
+    #[test]
+    fn test_ec_double_identity() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        assert_eq!(ec.double(&Point::Identity), Point::Identity);
+
+        // any field operation mod 0 would panic on a division by zero
+        let ec = EllipticCurve::new(
+            BigUint::from(0u32),
+            BigUint::from(0u32),
+            BigUint::from(0u32),
+        );
+        assert_eq!(ec.double(&Point::Identity), Point::Identity);
+        assert_eq!(ec.double_owned(Point::Identity), Point::Identity);
+    }
+
+    #[test]
+    fn test_ec_point_doubling_zero_slope() {
+        // y^2 = x^3 + 3x mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(0u32),
+            BigUint::from(17u32),
+        );
+
+        // 3 * 4^2 + 3 = 51 = 0 mod 17, with y = 5 != 0, so the tangent at (4, 5) is horizontal
+        let c = Point::Coordinate(BigUint::from(4u32), BigUint::from(5u32));
+        assert!(ec.is_on_curve(&c));
+        assert_eq!(
+            ec.double_slope(&BigUint::from(4u32), &BigUint::from(5u32)),
+            BigUint::from(0u32)
+        );
+
+        // x3 = 0 - 2 * 4 = 9 mod 17, y3 = 0 * (4 - 9) - 5 = 12 mod 17
+        let r = ec.double(&c);
+        assert_eq!(r, Point::Coordinate(BigUint::from(9u32), BigUint::from(12u32)));
+        assert!(ec.is_on_curve(&r));
+
+        // 2 * C agrees with the chord through C and 3 * C
+        let three_c = ec.add(&r, &c);
+        assert_eq!(ec.subtract(&three_c, &c), r);
+        assert_eq!(ec.scalar_multiplication(&c, &BigUint::from(2u32)), r);
+    }
+
+    #[test]
+    fn test_ec_is_valid_order() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        assert!(ec.is_valid_order(&g, &BigUint::from(19u32)));
+        // a multiple of the order, but not the order
+        assert!(!ec.is_valid_order(&g, &BigUint::from(38u32)));
+        assert!(!ec.is_valid_order(&g, &BigUint::from(18u32)));
+        assert!(!ec.is_valid_order(&g, &BigUint::from(0u32)));
+        assert!(ec.is_valid_order(&Point::Identity, &BigUint::from(1u32)));
+
+        // y^2 = x^3 + 2x + 3 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+        let c = Point::Coordinate(BigUint::from(3u32), BigUint::from(6u32));
+        let d = Point::Coordinate(BigUint::from(2u32), BigUint::from(7u32));
+        assert!(ec.is_valid_order(&c, &BigUint::from(11u32)));
+        assert!(!ec.is_valid_order(&c, &BigUint::from(22u32)));
+        assert!(ec.is_valid_order(&d, &BigUint::from(22u32)));
+        assert!(!ec.is_valid_order(&d, &BigUint::from(11u32)));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(&BigUint::from(1u32)), Some(vec![]));
+        assert_eq!(prime_factors(&BigUint::from(19u32)), Some(vec![BigUint::from(19u32)]));
+        assert_eq!(
+            prime_factors(&BigUint::from(360u32)),
+            Some(vec![BigUint::from(2u32), BigUint::from(3u32), BigUint::from(5u32)])
+        );
+
+        // two primes above 2^16: the product can be neither split nor trusted
+        let p = BigUint::from(65537u32);
+        let q = BigUint::from(65539u32);
+        assert_eq!(prime_factors(&(&p * &q)), None);
+        assert_eq!(prime_factors(&(&p * 4u32)), Some(vec![BigUint::from(2u32), p]));
+    }
+
+    #[test]
+    fn test_ec_points() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let points: Vec<Point> = ec.points().collect();
+        assert_eq!(points.len(), 19);
+        assert_eq!(points[0], Point::Identity);
+        assert_eq!(points[1], Point::Coordinate(BigUint::from(0u32), BigUint::from(6u32)));
+        assert_eq!(points[2], Point::Coordinate(BigUint::from(0u32), BigUint::from(11u32)));
+        for point in &points {
+            assert!(ec.is_on_curve(point), "{:?} is not on curve", point);
+        }
+
+        // every point is a multiple of (5, 1)
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        for d in 0u32..19 {
+            assert!(points.contains(&ec.scalar_multiplication(&a, &BigUint::from(d))));
+        }
+    }
+
+    #[test]
+    fn test_ec_count_points() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let count = ec.count_points();
+        assert_eq!(count, BigUint::from(19u32));
+        assert_eq!(count.clone() % BigUint::from(19u32), BigUint::from(0u32));
+        assert_eq!(count, BigUint::from(ec.points().count()));
+
+        // y^2 = x^3 + 2x + 3 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+        assert_eq!(ec.count_points(), BigUint::from(22u32));
+
+        // y^2 = x^3 + 3x^2 + x mod 101 in Weierstrass form, with a point of order 24
+        let ec = EllipticCurve::new(
+            BigUint::from(99u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+        );
+        assert_eq!(ec.count_points(), BigUint::from(96u32));
+        assert_eq!(ec.count_points(), BigUint::from(ec.points().count()));
+    }
+
+    #[test]
+    fn test_ec_points_with_order_two_point() {
+        // y^2 = x^3 + 2x + 3 mod 17 has 22 points, including (16, 0)
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+
+        let points: Vec<Point> = ec.points().collect();
+        assert_eq!(points.len(), 22);
+        assert_eq!(
+            points.iter().filter(|point| **point == Point::Coordinate(BigUint::from(16u32), BigUint::from(0u32))).count(),
+            1
+        );
+        for point in &points {
+            assert!(ec.is_on_curve(point), "{:?} is not on curve", point);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ec_points_large_curve() {
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p);
+
+        let _ = ec.points();
+    }
+
+    #[test]
+    fn test_ec_is_in_prime_subgroup() {
+        // y^2 = x^3 + 2x + 3 mod 17 has 22 = 2 * 11 points
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+        let order = BigUint::from(11u32);
+
+        // (3, 6) generates the subgroup of order 11
+        let a = Point::Coordinate(BigUint::from(3u32), BigUint::from(6u32));
+        assert!(ec.is_in_prime_subgroup(&a, &order));
+
+        // (16, 0) has order 2
+        let b = Point::Coordinate(BigUint::from(16u32), BigUint::from(0u32));
+        assert!(ec.is_on_curve(&b));
+        assert!(!ec.is_in_prime_subgroup(&b, &order));
+
+        // (2, 7) generates the whole group of order 22
+        let c = Point::Coordinate(BigUint::from(2u32), BigUint::from(7u32));
+        assert!(!ec.is_in_prime_subgroup(&c, &order));
+
+        // (3, 7) is not on the curve
+        let d = Point::Coordinate(BigUint::from(3u32), BigUint::from(7u32));
+        assert!(!ec.is_in_prime_subgroup(&d, &order));
+
+        // (20, 6) is (3, 6) with x left unreduced, and is rejected rather than panicking
+        let e = Point::Coordinate(BigUint::from(20u32), BigUint::from(6u32));
+        assert!(!ec.is_in_prime_subgroup(&e, &order));
+    }
+
+    #[test]
+    fn test_ec_scalar_multiplication_fixed() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        for window in 1..5 {
+            let table = ec.precompute_fixed_base(&a, window);
+            for d in [1u32, 2, 3, 7, 10, 16, 17, 18, 19, 20, 37, 38] {
+                let d = BigUint::from(d);
+                assert_eq!(
+                    ec.scalar_multiplication_fixed(&table, &d),
+                    ec.scalar_multiplication(&a, &d),
+                    "window = {window}, d = {d}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ec_precompute_fixed_base_window_too_large() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        ec.precompute_fixed_base(&a, 64);
+    }
+
+    #[test]
+    fn test_ec_scalar_multiplication_fixed_large_scalar() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        // scalars wider than the table fall back to scalar_multiplication
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let table = ec.precompute_fixed_base(&a, 2);
+        let d = BigUint::from(19u32 * 1000 + 10);
+        assert_eq!(
+            ec.scalar_multiplication_fixed(&table, &d),
+            ec.scalar_multiplication(&a, &BigUint::from(10u32))
+        );
+    }
+
+    #[test]
+    fn test_point_hash_set() {
+        extern crate std;
+        use std::collections::HashSet;
+
+        let p1 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let p2 = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let p3 = Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32));
+
+        let mut points = HashSet::new();
+        assert!(points.insert(p1.clone()));
+        assert!(points.insert(p2.clone()));
+        assert!(points.insert(Point::Identity));
+
+        // duplicates are not inserted again
+        assert!(!points.insert(p1.clone()));
+        assert!(!points.insert(Point::Identity));
+        assert_eq!(points.len(), 3);
+
+        assert!(points.contains(&p1));
+        assert!(points.contains(&p2));
+        assert!(points.contains(&Point::Identity));
+        assert!(!points.contains(&p3));
+    }
+
+    #[test]
+    fn test_point_hex_round_trip() {
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let gx = BigUint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16
+        ).expect("Could not convert gx");
+        let gy = BigUint::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16
+        ).expect("Could not convert gy");
+        let g = Point::Coordinate(gx, gy);
 
-        assert_eq!(r, BigUint::from(3u32));
+        let s = g.to_hex(&p);
+        assert_eq!(
+            s,
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+             483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+        assert_eq!(Point::from_hex(&s, &p), Some(g));
     }
 
     #[test]
-    fn test_add_two() {
-        let c = BigUint::from(4u32);
-        let d = BigUint::from(10u32);
-        let p = BigUint::from(31u32);
+    fn test_point_hex_small_curve() {
+        let p = BigUint::from(17u32);
 
-        let r = FiniteField::add(&c, &d, &p);
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(a.to_hex(&p), "0501");
+        assert_eq!(Point::from_hex("0501", &p), Some(a));
 
-        assert_eq!(r, BigUint::from(14u32));
+        assert_eq!(Point::Identity.to_hex(&p), "00");
+        assert_eq!(Point::from_hex("00", &p), Some(Point::Identity));
     }
 
     #[test]
-    fn test_multiplication_one() {
-        let c = BigUint::from(4u32);
-        let d = BigUint::from(10u32);
-        let p = BigUint::from(11u32);
-
-        let r = FiniteField::multiplication(&c, &d, &p);
+    fn test_point_from_hex_malformed() {
+        let p = BigUint::from(17u32);
 
-        assert_eq!(r, BigUint::from(7u32));
+        // wrong length
+        assert_eq!(Point::from_hex("050001", &p), None);
+        assert_eq!(Point::from_hex("", &p), None);
+        // not hex
+        assert_eq!(Point::from_hex("05zz", &p), None);
+        assert_eq!(Point::from_hex("+501", &p), None);
+        // coordinate not less than p
+        assert_eq!(Point::from_hex("1101", &p), None);
+        // right byte length, but the split would land inside a multi-byte character
+        assert_eq!(Point::from_hex("a\u{e9}0", &p), None);
     }
 
     #[test]
-    fn test_multiplication_two() {
-        let c = BigUint::from(4u32);
-        let d = BigUint::from(10u32);
-        let p = BigUint::from(51u32);
-
-        let r = FiniteField::multiplication(&c, &d, &p);
+    fn test_ec_scalar_multiplication_mod_order() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let n = BigUint::from(19u32);
+
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        for k in [1u32, 2, 10, 18] {
+            let k = BigUint::from(k);
+            let expected = ec.scalar_multiplication(&g, &k);
+            assert_eq!(ec.scalar_multiplication_mod_order(&g, &(&n + &k), &n), expected);
+            assert_eq!(
+                ec.scalar_multiplication_mod_order(&g, &(&n * 1000u32 + &k), &n),
+                expected
+            );
+        }
 
-        assert_eq!(r, BigUint::from(40u32));
+        assert_eq!(ec.scalar_multiplication_mod_order(&g, &n, &n), Point::Identity);
     }
 
     #[test]
-    fn test_inverse_addition_one() {
-        let c = BigUint::from(4u32);
-        let p = BigUint::from(31u32);
-
-        let r = FiniteField::inverse_addition(&c, &p);
-
-        assert_eq!(r, BigUint::from(27u32));
+    fn test_wnaf_digits() {
+        // 7 = 8 - 1 => [-1, 0, 0, 1]
+        assert_eq!(EllipticCurve::wnaf(&BigUint::from(7u32), 2), vec![-1, 0, 0, 1]);
+        // 7 with w = 3 => [-1, 0, 0, 1]
+        assert_eq!(EllipticCurve::wnaf(&BigUint::from(7u32), 3), vec![-1, 0, 0, 1]);
+        // 7 with w = 4 => [7]
+        assert_eq!(EllipticCurve::wnaf(&BigUint::from(7u32), 4), vec![7]);
+
+        for w in 2..8 {
+            for d in 0u32..1000 {
+                let digits = EllipticCurve::wnaf(&BigUint::from(d), w);
+                let value = digits.iter().rev().fold(0i64, |value, k| 2 * value + *k as i64);
+                assert_eq!(value, d as i64, "w = {w}, d = {d}");
+                for (i, k) in digits.iter().enumerate() {
+                    if *k != 0 {
+                        assert!(k % 2 != 0 && k.abs() < 1 << (w - 1), "w = {w}, d = {d}");
+                        assert!(digits[i + 1..].iter().take(w - 1).all(|k| *k == 0));
+                    }
+                }
+            }
+        }
     }
 
     #[test]
-    #[should_panic]
-    fn test_inverse_addition_two() {
-        let c = BigUint::from(32u32);
-        let p = BigUint::from(31u32);
+    fn test_ec_scalar_multiplication_wnaf() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
 
-        FiniteField::inverse_addition(&c, &p);
+        for w in 2..7 {
+            for d in 0u32..60 {
+                let d = BigUint::from(d);
+                assert_eq!(
+                    ec.scalar_multiplication_wnaf(&a, &d, w),
+                    ec.scalar_multiplication_mod_order(&a, &d, &BigUint::from(19u32)),
+                    "w = {w}, d = {d}"
+                );
+            }
+        }
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_inverse_addition_identity() {
-        let c = BigUint::from(4u32);
-        let p = BigUint::from(31u32);
+    fn test_ec_scalar_multiplication_wnaf_random() {
+        use rand::Rng;
 
-        let c_inverse = FiniteField::inverse_addition(&c, &p);
-        let r = FiniteField::add(&c, &c_inverse, &p);
+        let mut rng = rand::thread_rng();
 
-        assert_eq!(r, BigUint::from(0u32));
+        // y^2 = x^3 + 2x + 3 mod 17, with points of order 2, 11 and 22
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(3u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(2u32), BigUint::from(7u32));
+
+        for w in [2, 3, 4, 5, 8] {
+            for _ in 0..20 {
+                let d = BigUint::from(rng.gen::<u64>());
+                assert_eq!(
+                    ec.scalar_multiplication_wnaf(&a, &d, w),
+                    ec.scalar_multiplication_mod_order(&a, &d, &BigUint::from(22u32)),
+                    "w = {w}, d = {d}"
+                );
+            }
+        }
     }
 
     #[test]
-    fn test_subtract() {
-        let c = BigUint::from(4u32);
-        let p = BigUint::from(31u32);
+    fn test_ec_scalar_multiplication_past_order() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
 
-        assert_eq!(FiniteField::subtract(&c, &c, &p), BigUint::from(0u32))
+        // 21 * A doubles 10 * A into 20 * A = A before adding A again
+        let result = ec.scalar_multiplication(&a, &BigUint::from(21u32));
+        assert_eq!(result, ec.double(&a));
     }
 
     #[test]
-    fn test_inverse_multiplication_identity() {
-        let c = BigUint::from(4u32);
-        let p = BigUint::from(17u32);
+    fn test_ec_multi_scalar_mul() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let b = ec.scalar_multiplication(&a, &BigUint::from(7u32));
 
-        let c_inverse = FiniteField::inverse_multiplication(&c, &p);
-        let r = FiniteField::multiplication(&c, &c_inverse, &p);
+        // 3 * A + 2 * (7 * A) + 0 * A = 17 * A
+        let pairs = [
+            (a.clone(), BigUint::from(3u32)),
+            (b, BigUint::from(2u32)),
+            (a.clone(), BigUint::from(0u32)),
+        ];
+        assert_eq!(ec.multi_scalar_mul(&pairs), ec.scalar_multiplication(&a, &BigUint::from(17u32)));
 
-        assert_eq!(r, BigUint::from(1u32));
+        assert_eq!(ec.multi_scalar_mul(&[]), Point::Identity);
     }
 
+    #[cfg(feature = "rayon")]
     #[test]
-    fn test_divide() {
-        let c = BigUint::from(4u32);
-        let p = BigUint::from(11u32);
+    fn test_ec_multi_scalar_mul_parallel() {
+        let mut rng = rand::thread_rng();
 
-        assert_eq!(FiniteField::divide(&c, &c, &p), BigUint::from(1u32));
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let pairs: Vec<(Point, BigUint)> = (0..300)
+            .map(|_| (ec.random_point(&mut rng), BigUint::from(rng.gen::<u16>())))
+            .collect();
+
+        for length in [0, 1, 2, 7, 300] {
+            assert_eq!(
+                ec.multi_scalar_mul_parallel(&pairs[..length]),
+                ec.multi_scalar_mul_sequential(&pairs[..length]),
+                "length = {length}"
+            );
+        }
     }
 
     #[test]
-    fn test_ec_point_addition() {
+    fn test_ec_double_table() {
         // y^2 = x^3 + 2x + 2 mod 17
         let ec = EllipticCurve::new(
             BigUint::from(2u32),
             BigUint::from(2u32),
             BigUint::from(17u32),
         );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
 
-        // (6, 3) + (5, 1) = (10, 6)
-        let p1 = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
-        let p2 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
-        let p3 = Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32));
-
-        let result = ec.add(&p1, &p2);
-        assert_eq!(result, p3);
+        let table = ec.double_table(&a, 4);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table[0], a);
+        assert_eq!(table[1], ec.double(&a));
+        assert_eq!(table[2], ec.scalar_multiplication(&a, &BigUint::from(4u32)));
+        assert_eq!(table[3], ec.scalar_multiplication(&a, &BigUint::from(8u32)));
     }
 
     #[test]
-    fn test_ec_point_doubling() {
+    fn test_ec_scalar_multiplication_with_table() {
         // y^2 = x^3 + 2x + 2 mod 17
         let ec = EllipticCurve::new(
             BigUint::from(2u32),
             BigUint::from(2u32),
             BigUint::from(17u32),
         );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
 
-        // (5, 1) + (5, 1) = 2 * (5, 1) = (6, 3)
-        let p1 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
-        let pr = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
-        let result = ec.double(&p1);
-        assert_eq!(result, pr);
+        let table = ec.double_table(&a, 8);
+        for d in 0u32..256 {
+            let d = BigUint::from(d);
+            assert_eq!(
+                ec.scalar_multiplication_with_table(&table, &d),
+                ec.scalar_multiplication(&a, &d),
+                "d = {d}"
+            );
+        }
     }
 
     #[test]
-    fn test_ec_point_doubling_identity() {
+    #[should_panic]
+    fn test_ec_scalar_multiplication_with_table_too_short() {
         // y^2 = x^3 + 2x + 2 mod 17
         let ec = EllipticCurve::new(
             BigUint::from(2u32),
             BigUint::from(2u32),
             BigUint::from(17u32),
         );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
 
-        // Point::Identity + Point::Identity = 2 * Point::Identity = Point::Identity
-        let p1 = Point::Identity;
-        let pr = Point::Identity;
-
-        let result = ec.double(&p1);
-        assert_eq!(result, pr);
+        let table = ec.double_table(&a, 4);
+        ec.scalar_multiplication_with_table(&table, &BigUint::from(16u32));
     }
 
     #[test]
-    fn test_ec_scalar_multiplication() {
+    fn test_ec_scalar_multiplication_zero() {
         // y^2 = x^3 + 2x + 2 mod 17
         let ec = EllipticCurve::new(
             BigUint::from(2u32),
@@ -348,36 +2901,118 @@ mod test {
         );
 
         let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let result = ec.scalar_multiplication(&a, &BigUint::from(0u32));
+        assert_eq!(result, Point::Identity);
+    }
 
-        // 2 * (5, 1) = (6, 3)
-        let pr = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
-        let result = ec.scalar_multiplication(&a, &BigUint::from(2u32));
-        assert_eq!(result, pr);
+    #[test]
+    fn test_point_bytes_round_trip() {
+        let gx = BigUint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16
+        ).expect("Could not convert gx");
+        let gy = BigUint::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16
+        ).expect("Could not convert gy");
+        let g = Point::Coordinate(gx, gy);
 
-        // 10 * (5, 1) = (7, 11)
-        let pr = Point::Coordinate(BigUint::from(7u32), BigUint::from(11u32));
-        let result = ec.scalar_multiplication(&a, &BigUint::from(10u32));
-        assert_eq!(result, pr);
+        let bytes = g.to_bytes();
+        assert_eq!(bytes.len(), 1 + 4 + 32 + 4 + 32);
+        assert_eq!(Point::from_bytes(&bytes), Some(g));
 
-        // 16 * (5, 1) = (10, 11)
-        let pr = Point::Coordinate(BigUint::from(10u32), BigUint::from(11u32));
-        let result = ec.scalar_multiplication(&a, &BigUint::from(16u32));
-        assert_eq!(result, pr);
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let bytes = a.to_bytes();
+        assert_eq!(bytes, vec![0x01, 0, 0, 0, 1, 5, 0, 0, 0, 1, 1]);
+        assert_eq!(Point::from_bytes(&bytes), Some(a));
 
-        // 17 * (5, 1) = (6, 14)
-        let pr = Point::Coordinate(BigUint::from(6u32), BigUint::from(14u32));
-        let result = ec.scalar_multiplication(&a, &BigUint::from(17u32));
-        assert_eq!(result, pr);
+        let bytes = Point::Identity.to_bytes();
+        assert_eq!(bytes, vec![0x00]);
+        assert_eq!(Point::from_bytes(&bytes), Some(Point::Identity));
+    }
 
-        // 18 * (5, 1) = (5, 16)
-        let pr = Point::Coordinate(BigUint::from(5u32), BigUint::from(16u32));
-        let result = ec.scalar_multiplication(&a, &BigUint::from(18u32));
-        assert_eq!(result, pr);
+    #[test]
+    fn test_point_from_bytes_malformed() {
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let bytes = a.to_bytes();
 
-        // 19 * (5, 1) = Point::Identity
-        let pr = Point::Identity;
-        let result = ec.scalar_multiplication(&a, &BigUint::from(19u32));
-        assert_eq!(result, pr);
+        // truncated at every possible length
+        for length in 0..bytes.len() {
+            assert_eq!(Point::from_bytes(&bytes[..length]), None, "length = {length}");
+        }
+
+        // trailing bytes
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(Point::from_bytes(&trailing), None);
+        assert_eq!(Point::from_bytes(&[0x00, 0x00]), None);
+
+        // unknown tag
+        assert_eq!(Point::from_bytes(&[0x02]), None);
+
+        // length prefix larger than the input
+        assert_eq!(Point::from_bytes(&[0x01, 0xff, 0xff, 0xff, 0xff, 5]), None);
+    }
+
+    #[test]
+    fn test_decoders_malformed_corpus() {
+        // none of the decoders may panic on untrusted input
+        let p = BigUint::from(17u32);
+        let order = BigUint::from(19u32);
+
+        let mut corpus: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0x00],
+            vec![0x01],
+            vec![0xff; 4],
+            vec![0x01, 0x00, 0x00, 0x00, 0x01],
+            vec![0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            vec![0x01, 0x80, 0x00, 0x00, 0x00],
+            "a\u{e9}0".as_bytes().to_vec(),
+            "\u{e9}\u{e9}".as_bytes().to_vec(),
+            b"1111".to_vec(),
+            b"ffff".to_vec(),
+            b"0x05".to_vec(),
+        ];
+        // xorshift, so the corpus is the same on every run
+        let mut state = 0x2545f491u32;
+        for length in 0..64 {
+            let bytes = (0..length)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    state as u8
+                })
+                .collect();
+            corpus.push(bytes);
+        }
+
+        for bytes in &corpus {
+            let _ = Point::from_bytes(bytes);
+            if let Ok(s) = core::str::from_utf8(bytes) {
+                if let Some(Point::Coordinate(x, y)) = Point::from_hex(s, &p) {
+                    assert!(x < p && y < p);
+                }
+                if let Some(d) = scalar::from_hex(s, &order) {
+                    assert!(d < order);
+                }
+            }
+            let s = String::from_utf8_lossy(bytes);
+            let _ = Point::from_hex(&s, &p);
+            let _ = scalar::from_hex(&s, &order);
+        }
+    }
+
+    #[test]
+    fn test_point_display() {
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        assert_eq!(format!("{a}"), "(0x5, 0x1)");
+
+        let b = Point::Coordinate(BigUint::from(255u32), BigUint::from(16u32));
+        assert_eq!(format!("{b}"), "(0xff, 0x10)");
+
+        assert_eq!(format!("{}", Point::Identity), "O");
     }
 
     #[test]
@@ -432,4 +3067,93 @@ mod test {
 
         assert_eq!(result, Point::Identity);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_point_secp256k1_generator() {
+        let gx = BigUint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16
+        ).expect("Could not convert gx");
+        let gy = BigUint::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16
+        ).expect("Could not convert gy");
+        let g = Point::Coordinate(gx, gy);
+
+        let json = serde_json::to_string(&g).expect("Could not serialize g");
+        assert_eq!(
+            json,
+            r#"{"Coordinate":["79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798","483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"]}"#
+        );
+
+        let result: Point = serde_json::from_str(&json).expect("Could not deserialize g");
+        assert_eq!(result, g);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_point_identity() {
+        let json = serde_json::to_string(&Point::Identity).expect("Could not serialize identity");
+        assert_eq!(json, r#""Identity""#);
+
+        let result: Point = serde_json::from_str(&json).expect("Could not deserialize identity");
+        assert_eq!(result, Point::Identity);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_elliptic_curve() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+
+        let json = serde_json::to_string(&ec).expect("Could not serialize ec");
+        assert_eq!(json, r#"{"a":"2","b":"2","p":"11"}"#);
+
+        let result: EllipticCurve = serde_json::from_str(&json).expect("Could not deserialize ec");
+        assert_eq!(result, ec);
+
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        for d in [2u32, 10, 16, 17, 18, 19] {
+            let d = BigUint::from(d);
+            assert_eq!(result.scalar_multiplication(&a, &d), ec.scalar_multiplication(&a, &d));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_from_reader() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        let json = serde_json::to_vec(&ec).expect("Could not serialize ec");
+        let result: EllipticCurve =
+            serde_json::from_reader(json.as_slice()).expect("Could not deserialize ec");
+        assert_eq!(result, ec);
+
+        let json = serde_json::to_vec(&a).expect("Could not serialize a");
+        let result: Point =
+            serde_json::from_reader(json.as_slice()).expect("Could not deserialize a");
+        assert_eq!(result, a);
+
+        let value = serde_json::to_value(&a).expect("Could not serialize a");
+        let result: Point = serde_json::from_value(value).expect("Could not deserialize a");
+        assert_eq!(result, a);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_invalid_hex() {
+        let result = serde_json::from_str::<Point>(r#"{"Coordinate":["5","xyz"]}"#);
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,355 @@
+use core::mem;
+
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, FiniteField, Point};
+
+// Montgomery-form curves B * y^2 = x^3 + A * x^2 + x mod p, with an x-only ladder.
+//
+// The ladder works on x-coordinates alone, so Identity has no x to return; it is reported as 0,
+// the same convention X25519 uses. The point (0, 0) has order 2 and maps to 0 as well.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MontgomeryCurve {
+    a: BigUint,
+    b: BigUint,
+    p: BigUint,
+}
+
+impl MontgomeryCurve {
+    pub fn new(a: BigUint, b: BigUint, p: BigUint) -> Self {
+        assert!(!is_singular(&a, &b, &p), "B = 0 or A^2 = 4 mod p, the curve is singular");
+
+        Self { a, b, p }
+    }
+
+    pub fn a(&self) -> &BigUint {
+        &self.a
+    }
+
+    pub fn b(&self) -> &BigUint {
+        &self.b
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.p
+    }
+
+    pub fn is_on_curve(&self, c: &Point) -> bool {
+        if let Point::Coordinate(x, y) = c {
+            // coordinates must be reduced mod p, as for EllipticCurve::is_on_curve
+            if *x >= self.p || *y >= self.p {
+                return false;
+            }
+
+            // B * y^2 = x^3 + A * x^2 + x
+            let y_square = FiniteField::pow(y, &BigUint::from(2u32), &self.p);
+            let lhs = FiniteField::multiplication(&self.b, &y_square, &self.p);
+
+            let x_squared = FiniteField::pow(x, &BigUint::from(2u32), &self.p);
+            let x_cubed = FiniteField::multiplication(&x_squared, x, &self.p);
+            let ax_squared = FiniteField::multiplication(&self.a, &x_squared, &self.p);
+            let rhs = FiniteField::add(
+                &FiniteField::add(&x_cubed, &ax_squared, &self.p),
+                x,
+                &self.p,
+            );
+            lhs == rhs
+        } else {
+            true
+        }
+    }
+
+    pub fn to_weierstrass(&self) -> EllipticCurve {
+        // a = (3 - A^2) / (3 * B^2) mod p
+        // b = (2 * A^3 - 9 * A) / (27 * B^3) mod p
+        let p = &self.p;
+        let a_squared = FiniteField::pow(&self.a, &BigUint::from(2u32), p);
+        let a_cubed = FiniteField::multiplication(&a_squared, &self.a, p);
+        let b_squared = FiniteField::pow(&self.b, &BigUint::from(2u32), p);
+        let b_cubed = FiniteField::multiplication(&b_squared, &self.b, p);
+
+        let a = FiniteField::divide(
+            &FiniteField::subtract(&(BigUint::from(3u32) % p), &a_squared, p),
+            &FiniteField::multiplication(&(BigUint::from(3u32) % p), &b_squared, p),
+            p,
+        );
+        let b = FiniteField::divide(
+            &FiniteField::subtract(
+                &FiniteField::multiplication(&(BigUint::from(2u32) % p), &a_cubed, p),
+                &FiniteField::multiplication(&(BigUint::from(9u32) % p), &self.a, p),
+                p,
+            ),
+            &FiniteField::multiplication(&(BigUint::from(27u32) % p), &b_cubed, p),
+            p,
+        );
+        EllipticCurve::new(a, b, p.clone())
+    }
+
+    pub fn from_weierstrass(ec: &EllipticCurve, alpha: &BigUint) -> Option<MontgomeryCurve> {
+        // only curves with a root alpha of x^3 + ax + b, and with 3 * alpha^2 + a a square, have
+        // a Montgomery form; for s = 1 / sqrt(3 * alpha^2 + a)
+        // A = 3 * alpha * s mod p
+        // B = s mod p
+        //
+        // finding alpha is left to the caller
+        let p = ec.modulus();
+        if *alpha >= *p || !ec.is_on_curve(&Point::Coordinate(alpha.clone(), BigUint::from(0u32))) {
+            return None;
+        }
+
+        let alpha_squared = FiniteField::pow(alpha, &BigUint::from(2u32), p);
+        let t = FiniteField::add(
+            &FiniteField::multiplication(&(BigUint::from(3u32) % p), &alpha_squared, p),
+            ec.a(),
+            p,
+        );
+        if t == BigUint::from(0u32) {
+            return None;
+        }
+        let s = FiniteField::inverse_multiplication(&FiniteField::sqrt(&t, p)?, p);
+
+        let a = FiniteField::multiplication(
+            &FiniteField::multiplication(&(BigUint::from(3u32) % p), alpha, p),
+            &s,
+            p,
+        );
+        if is_singular(&a, &s, p) {
+            return None;
+        }
+        Some(MontgomeryCurve::new(a, s, p.clone()))
+    }
+
+    pub fn to_weierstrass_point(&self, c: &Point) -> Point {
+        // (x, y) => ((x + A / 3) / B, y / B) on the curve from to_weierstrass
+        assert!(self.is_on_curve(c), "{:?} is not on curve", c);
+
+        match c {
+            Point::Coordinate(x, y) => {
+                let p = &self.p;
+                let a_third = FiniteField::divide(&self.a, &(BigUint::from(3u32) % p), p);
+                Point::Coordinate(
+                    FiniteField::divide(&FiniteField::add(x, &a_third, p), &self.b, p),
+                    FiniteField::divide(y, &self.b, p),
+                )
+            }
+            Point::Identity => Point::Identity,
+        }
+    }
+
+    pub fn from_weierstrass_point(&self, c: &Point) -> Point {
+        // (x, y) => (B * x - A / 3, B * y), the inverse of to_weierstrass_point
+        assert!(self.to_weierstrass().is_on_curve(c), "{:?} is not on curve", c);
+
+        match c {
+            Point::Coordinate(x, y) => {
+                let p = &self.p;
+                let a_third = FiniteField::divide(&self.a, &(BigUint::from(3u32) % p), p);
+                Point::Coordinate(
+                    FiniteField::subtract(
+                        &FiniteField::multiplication(&self.b, x, p),
+                        &a_third,
+                        p,
+                    ),
+                    FiniteField::multiplication(&self.b, y, p),
+                )
+            }
+            Point::Identity => Point::Identity,
+        }
+    }
+
+    pub fn ladder(&self, u: &BigUint, scalar: &BigUint) -> BigUint {
+        // x-coordinate of scalar * (u, _), on projective (X : Z) pairs
+        //
+        // each step keeps (x2 : z2) = k * P and (x3 : z3) = (k + 1) * P, so their difference is
+        // always P and a differential addition with x1 = u gives the next pair
+        assert!(*u < self.p, "{u} >= {}", self.p);
+
+        let p = &self.p;
+        let add = |c: &BigUint, d: &BigUint| FiniteField::add(c, d, p);
+        let subtract = |c: &BigUint, d: &BigUint| FiniteField::subtract(c, d, p);
+        let multiply = |c: &BigUint, d: &BigUint| FiniteField::multiplication(c, d, p);
+
+        // a24 = (A - 2) / 4
+        let a24 = FiniteField::divide(
+            &subtract(&self.a, &BigUint::from(2u32)),
+            &(BigUint::from(4u32) % p),
+            p,
+        );
+
+        let x1 = u.clone();
+        let (mut x2, mut z2) = (BigUint::from(1u32), BigUint::from(0u32));
+        let (mut x3, mut z3) = (u.clone(), BigUint::from(1u32));
+
+        for i in (0..scalar.bits()).rev() {
+            let bit = scalar.bit(i);
+            if bit {
+                mem::swap(&mut x2, &mut x3);
+                mem::swap(&mut z2, &mut z3);
+            }
+
+            let a = add(&x2, &z2);
+            let aa = multiply(&a, &a);
+            let b = subtract(&x2, &z2);
+            let bb = multiply(&b, &b);
+            let e = subtract(&aa, &bb);
+            let c = add(&x3, &z3);
+            let d = subtract(&x3, &z3);
+            let da = multiply(&d, &a);
+            let cb = multiply(&c, &b);
+
+            let da_plus_cb = add(&da, &cb);
+            let da_minus_cb = subtract(&da, &cb);
+            x3 = multiply(&da_plus_cb, &da_plus_cb);
+            z3 = multiply(&x1, &multiply(&da_minus_cb, &da_minus_cb));
+            x2 = multiply(&aa, &bb);
+            z2 = multiply(&e, &add(&aa, &multiply(&a24, &e)));
+
+            if bit {
+                mem::swap(&mut x2, &mut x3);
+                mem::swap(&mut z2, &mut z3);
+            }
+        }
+
+        // x = X / Z, with Z = 0 for Identity
+        if z2 == BigUint::from(0u32) {
+            return BigUint::from(0u32);
+        }
+        FiniteField::divide(&x2, &z2, p)
+    }
+}
+
+fn is_singular(a: &BigUint, b: &BigUint, p: &BigUint) -> bool {
+    // B * y^2 = x^3 + A * x^2 + x is singular when B = 0 or A^2 = 4 mod p
+    b % p == BigUint::from(0u32) || (a * a) % p == BigUint::from(4u32) % p
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_montgomery_is_on_curve() {
+        // y^2 = x^3 + 3x^2 + x mod 101
+        let mc = MontgomeryCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+        );
+
+        assert!(mc.is_on_curve(&Point::Coordinate(BigUint::from(24u32), BigUint::from(27u32))));
+        assert!(mc.is_on_curve(&Point::Coordinate(BigUint::from(0u32), BigUint::from(0u32))));
+        assert!(mc.is_on_curve(&Point::Identity));
+        assert!(!mc.is_on_curve(&Point::Coordinate(BigUint::from(24u32), BigUint::from(28u32))));
+
+        // (24, 128) and (125, 27) are (24, 27) with a coordinate left unreduced
+        assert!(!mc.is_on_curve(&Point::Coordinate(BigUint::from(24u32), BigUint::from(128u32))));
+        assert!(!mc.is_on_curve(&Point::Coordinate(BigUint::from(125u32), BigUint::from(27u32))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_montgomery_new_b_zero() {
+        MontgomeryCurve::new(BigUint::from(3u32), BigUint::from(101u32), BigUint::from(101u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_montgomery_new_a_squared_four() {
+        // 99^2 = (-2)^2 = 4 mod 101
+        MontgomeryCurve::new(BigUint::from(99u32), BigUint::from(1u32), BigUint::from(101u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_montgomery_from_weierstrass_point_off_curve() {
+        // y^2 = x^3 + 3x^2 + x mod 101
+        let mc = MontgomeryCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+        );
+
+        mc.from_weierstrass_point(&Point::Coordinate(BigUint::from(1u32), BigUint::from(1u32)));
+    }
+
+    #[test]
+    fn test_montgomery_to_weierstrass() {
+        // y^2 = x^3 + 3x^2 + x mod 101, where (24, 27) has order 24
+        let mc = MontgomeryCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+        );
+        let c = Point::Coordinate(BigUint::from(24u32), BigUint::from(27u32));
+
+        // a = (3 - 9) / 3 = -2, b = (54 - 27) / 27 = 1
+        let ec = mc.to_weierstrass();
+        assert_eq!(
+            ec,
+            EllipticCurve::new(BigUint::from(99u32), BigUint::from(1u32), BigUint::from(101u32))
+        );
+
+        let d = mc.to_weierstrass_point(&c);
+        assert!(ec.is_on_curve(&d));
+        assert_eq!(mc.from_weierstrass_point(&d), c);
+        assert_eq!(mc.to_weierstrass_point(&Point::Identity), Point::Identity);
+
+        let u = BigUint::from(24u32);
+        for k in 1u32..=24 {
+            let k = BigUint::from(k);
+            let x = match mc.from_weierstrass_point(&ec.scalar_multiplication(&d, &k)) {
+                Point::Coordinate(x, _) => x,
+                Point::Identity => BigUint::from(0u32),
+            };
+            assert_eq!(mc.ladder(&u, &k), x, "{k} * P");
+        }
+    }
+
+    #[test]
+    fn test_montgomery_from_weierstrass() {
+        // y^2 = x^3 - 2x + 1 mod 101, the Weierstrass form of y^2 = x^3 + 3x^2 + x
+        let ec = EllipticCurve::new(
+            BigUint::from(99u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+        );
+
+        // alpha = 1 is the image of (0, 0)
+        let mc = MontgomeryCurve::from_weierstrass(&ec, &BigUint::from(1u32))
+            .expect("1 is a root");
+        assert!(*mc.a() == BigUint::from(3u32) || *mc.a() == BigUint::from(98u32));
+        assert_eq!(mc.to_weierstrass(), ec);
+        assert_eq!(
+            mc.from_weierstrass_point(&Point::Coordinate(BigUint::from(1u32), BigUint::from(0u32))),
+            Point::Coordinate(BigUint::from(0u32), BigUint::from(0u32))
+        );
+
+        // 2 is not a root of x^3 - 2x + 1
+        assert_eq!(MontgomeryCurve::from_weierstrass(&ec, &BigUint::from(2u32)), None);
+    }
+
+    #[test]
+    fn test_montgomery_ladder() {
+        // y^2 = x^3 + 3x^2 + x mod 101, where (24, 27) has order 24
+        let mc = MontgomeryCurve::new(
+            BigUint::from(3u32),
+            BigUint::from(1u32),
+            BigUint::from(101u32),
+        );
+        let u = BigUint::from(24u32);
+
+        // x(k * P) for k = 1..=24, from affine additions; 12 * P = (0, 0) and 24 * P = Identity
+        let expected = [
+            24u32, 97, 14, 54, 84, 100, 95, 58, 65, 25, 80, 0, 80, 25, 65, 58, 95, 100, 84, 54,
+            14, 97, 24, 0,
+        ];
+        for (k, x) in (1u32..).zip(expected) {
+            assert_eq!(mc.ladder(&u, &BigUint::from(k)), BigUint::from(x), "{k} * P");
+        }
+
+        // scalars wrap around the order of P
+        assert_eq!(mc.ladder(&u, &BigUint::from(25u32)), u);
+        assert_eq!(mc.ladder(&u, &BigUint::from(0u32)), BigUint::from(0u32));
+    }
+}
@@ -0,0 +1,31 @@
+// Built only by `cargo test --no-default-features`: exercises the curve arithmetic with the
+// crate compiled as `no_std`, using nothing beyond `core` and `alloc`.
+
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, Point};
+
+#[test]
+fn test_no_std_scalar_multiplication() {
+    // y^2 = x^3 + 2x + 2 mod 17
+    let ec = EllipticCurve::new(
+        BigUint::from(2u32),
+        BigUint::from(2u32),
+        BigUint::from(17u32),
+    );
+    let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+    let multiples: Vec<Point> = [2u32, 10, 16, 17, 18, 19]
+        .iter()
+        .map(|d| ec.scalar_multiplication(&a, &BigUint::from(*d)))
+        .collect();
+
+    assert_eq!(multiples[0], Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32)));
+    assert_eq!(multiples[1], Point::Coordinate(BigUint::from(7u32), BigUint::from(11u32)));
+    assert_eq!(multiples[2], Point::Coordinate(BigUint::from(10u32), BigUint::from(11u32)));
+    assert_eq!(multiples[3], Point::Coordinate(BigUint::from(6u32), BigUint::from(14u32)));
+    assert_eq!(multiples[4], Point::Coordinate(BigUint::from(5u32), BigUint::from(16u32)));
+    assert_eq!(multiples[5], Point::Identity);
+}
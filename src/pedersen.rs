@@ -0,0 +1,80 @@
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, Point};
+
+pub fn commit(
+    ec: &EllipticCurve,
+    g: &Point,
+    h: &Point,
+    value: &BigUint,
+    blinding: &BigUint,
+) -> Point {
+    // C = v * G + r * H
+    //
+    // H must be a generator whose discrete logarithm to G is unknown, e.g.
+    // ec.hash_to_point(..) of a public seed
+    let vg = ec.scalar_multiplication(g, value);
+    let rh = ec.scalar_multiplication(h, blinding);
+    ec.add(&vg, &rh)
+}
+
+pub fn verify(
+    ec: &EllipticCurve,
+    g: &Point,
+    h: &Point,
+    commitment: &Point,
+    value: &BigUint,
+    blinding: &BigUint,
+) -> bool {
+    commit(ec, g, h, value, blinding) == *commitment
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_commit_verify() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let h = ec.hash_to_point(b"pedersen H");
+
+        let value = BigUint::from(7u32);
+        let blinding = BigUint::from(11u32);
+        let commitment = commit(&ec, &g, &h, &value, &blinding);
+
+        assert!(verify(&ec, &g, &h, &commitment, &value, &blinding));
+        assert!(!verify(&ec, &g, &h, &commitment, &BigUint::from(8u32), &blinding));
+        assert!(!verify(&ec, &g, &h, &commitment, &value, &BigUint::from(12u32)));
+    }
+
+    #[test]
+    fn test_commit_homomorphic() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let h = ec.hash_to_point(b"pedersen H");
+        let order = BigUint::from(19u32);
+        assert_ne!(h, g);
+
+        for (v1, r1, v2, r2) in [(3u32, 5u32, 4u32, 6u32), (10, 18, 15, 2), (0, 1, 18, 18)] {
+            let (v1, r1) = (BigUint::from(v1), BigUint::from(r1));
+            let (v2, r2) = (BigUint::from(v2), BigUint::from(r2));
+
+            // commit(v1, r1) + commit(v2, r2) = commit(v1 + v2, r1 + r2)
+            let c1 = commit(&ec, &g, &h, &v1, &r1);
+            let c2 = commit(&ec, &g, &h, &v2, &r2);
+            let c3 = commit(&ec, &g, &h, &((&v1 + &v2) % &order), &((&r1 + &r2) % &order));
+            assert_eq!(ec.add(&c1, &c2), c3);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use num_bigint::BigUint;
+
+use crate::{FiniteField, Point};
+
+// Jacobian coordinates: (X, Y, Z) represents the affine point (X / Z^2, Y / Z^3), and any
+// triple with Z = 0 represents Identity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectivePoint {
+    x: BigUint,
+    y: BigUint,
+    z: BigUint,
+}
+
+impl ProjectivePoint {
+    pub fn new(x: BigUint, y: BigUint, z: BigUint) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn x(&self) -> &BigUint {
+        &self.x
+    }
+
+    pub fn y(&self) -> &BigUint {
+        &self.y
+    }
+
+    pub fn z(&self) -> &BigUint {
+        &self.z
+    }
+
+    pub fn from_affine(point: &Point) -> Self {
+        // (x, y) => (x, y, 1)
+        // Identity => (1, 1, 0)
+        match point {
+            Point::Coordinate(x, y) => Self::new(x.clone(), y.clone(), BigUint::from(1u32)),
+            Point::Identity => Self::new(
+                BigUint::from(1u32),
+                BigUint::from(1u32),
+                BigUint::from(0u32),
+            ),
+        }
+    }
+
+    pub fn to_affine(&self, p: &BigUint) -> Point {
+        // (X, Y, Z) => (X / Z^2, Y / Z^3) with a single inversion of Z
+        let z = &self.z % p;
+        if z == BigUint::from(0u32) {
+            return Point::Identity;
+        }
+
+        let z_inverse = FiniteField::inverse_multiplication_ct(&z, p);
+        let z_inverse_squared = FiniteField::multiplication(&z_inverse, &z_inverse, p);
+        let z_inverse_cubed = FiniteField::multiplication(&z_inverse_squared, &z_inverse, p);
+        Point::Coordinate(
+            FiniteField::multiplication(&(&self.x % p), &z_inverse_squared, p),
+            FiniteField::multiplication(&(&self.y % p), &z_inverse_cubed, p),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::EllipticCurve;
+
+    #[test]
+    fn test_round_trip_small_curve() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let p = BigUint::from(17u32);
+        let a = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        for d in 0u32..19 {
+            let point = ec.scalar_multiplication(&a, &BigUint::from(d));
+            let projective = ProjectivePoint::from_affine(&point);
+            assert_eq!(projective.to_affine(&p), point, "d = {d}");
+        }
+    }
+
+    #[test]
+    fn test_round_trip_secp256k1() {
+        let p = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16
+        ).expect("Could not convert p");
+        let gx = BigUint::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16
+        ).expect("Could not convert gx");
+        let gy = BigUint::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16
+        ).expect("Could not convert gy");
+        let ec = EllipticCurve::new(BigUint::from(0u32), BigUint::from(7u32), p.clone());
+        let g = Point::Coordinate(gx, gy);
+
+        for point in [
+            g.clone(),
+            ec.double(&g),
+            ec.scalar_multiplication(&g, &BigUint::from(12345u32)),
+            Point::Identity,
+        ] {
+            let projective = ProjectivePoint::from_affine(&point);
+            assert_eq!(projective.to_affine(&p), point);
+        }
+    }
+
+    #[test]
+    fn test_to_affine_scaled() {
+        // (x * z^2, y * z^3, z) represents (x, y) for any non-zero z
+        let p = BigUint::from(17u32);
+        let (x, y) = (BigUint::from(5u32), BigUint::from(1u32));
+
+        for z in 1u32..17 {
+            let z = BigUint::from(z);
+            let projective = ProjectivePoint::new(
+                (&x * &z * &z) % &p,
+                (&y * &z * &z * &z) % &p,
+                z,
+            );
+            assert_eq!(projective.to_affine(&p), Point::Coordinate(x.clone(), y.clone()));
+        }
+    }
+
+    #[test]
+    fn test_to_affine_identity() {
+        let p = BigUint::from(17u32);
+
+        let projective = ProjectivePoint::new(
+            BigUint::from(3u32),
+            BigUint::from(4u32),
+            BigUint::from(0u32),
+        );
+        assert_eq!(projective.to_affine(&p), Point::Identity);
+
+        // Z = p is Z = 0 mod p
+        let projective = ProjectivePoint::new(
+            BigUint::from(3u32),
+            BigUint::from(4u32),
+            BigUint::from(17u32),
+        );
+        assert_eq!(projective.to_affine(&p), Point::Identity);
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+use crate::{byte_length, decode_fixed_hex, encode_fixed_hex};
+
+// Scalars (private keys, nonces, signature components) are encoded as a fixed-width,
+// zero-padded lowercase hex string whose width is the byte length of the group order.
+// A pair of scalars (r, s) can also be packed as the raw bytes r || s, each padded to
+// that same width.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scalar {
+    value: BigUint,
+    order: BigUint,
+}
+
+impl Scalar {
+    pub fn from_bytes_reduced(bytes: &[u8], order: &BigUint) -> Scalar {
+        // big-endian bytes, reduced into [0, order)
+        assert!(*order != BigUint::from(0u32), "order should be non-zero");
+
+        Scalar {
+            value: BigUint::from_bytes_be(bytes) % order,
+            order: order.clone(),
+        }
+    }
+
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    pub fn order(&self) -> &BigUint {
+        &self.order
+    }
+
+    pub fn into_value(self) -> BigUint {
+        self.value
+    }
+}
+
+pub fn hash_to_scalar(message: &[u8], order: &BigUint) -> Scalar {
+    // the leftmost bits(q) bits of H(m), reduced mod q, as FIPS 186 derives a message hash
+    //
+    // a q at least as long as the digest takes the whole digest
+    assert!(*order != BigUint::from(0u32), "order should be non-zero");
+
+    let digest = Sha256::digest(message);
+    let digest_bits = 8 * digest.len() as u64;
+    let mut e = BigUint::from_bytes_be(&digest);
+    if order.bits() < digest_bits {
+        e >>= digest_bits - order.bits();
+    }
+    Scalar {
+        value: e % order,
+        order: order.clone(),
+    }
+}
+
+pub fn hash_to_scalar_with_domain(domain: &[u8], message: &[u8], order: &BigUint) -> Scalar {
+    // H(len(domain) || domain || m) mod q, with len(domain) as 4 byte big-endian
+    hash_to_scalar(&domain_separated(domain, message), order)
+}
+
+pub(crate) fn domain_separated(domain: &[u8], message: &[u8]) -> Vec<u8> {
+    // the length prefix keeps (domain, message) pairs from running into each other, e.g.
+    // ("ab", "c") and ("a", "bc")
+    let length = u32::try_from(domain.len()).expect("domain is shorter than 2^32 bytes");
+
+    let mut bytes = Vec::with_capacity(4 + domain.len() + message.len());
+    bytes.extend_from_slice(&length.to_be_bytes());
+    bytes.extend_from_slice(domain);
+    bytes.extend_from_slice(message);
+    bytes
+}
+
+pub fn to_hex(d: &BigUint, order: &BigUint) -> String {
+    assert!(d < order, "{d} >= {order}");
+
+    encode_fixed_hex(d, byte_length(order))
+}
+
+pub fn from_hex(s: &str, order: &BigUint) -> Option<BigUint> {
+    let d = decode_fixed_hex(s, byte_length(order))?;
+    if d < *order {
+        Some(d)
+    } else {
+        None
+    }
+}
+
+pub fn signature_to_bytes(signature: &(BigUint, BigUint), order: &BigUint) -> Vec<u8> {
+    // r || s, each zero-padded big-endian to the byte length of the group order
+    let (r, s) = signature;
+    assert!(r < order, "{r} >= {order}");
+    assert!(s < order, "{s} >= {order}");
+
+    let width = byte_length(order);
+    let mut bytes = Vec::with_capacity(2 * width);
+    for component in [r, s] {
+        let component = component.to_bytes_be();
+        bytes.resize(bytes.len() + width - component.len(), 0);
+        bytes.extend_from_slice(&component);
+    }
+    bytes
+}
+
+pub fn signature_from_bytes(bytes: &[u8], order: &BigUint) -> Option<(BigUint, BigUint)> {
+    let width = byte_length(order);
+    if bytes.len() != 2 * width {
+        return None;
+    }
+    let (r, s) = bytes.split_at(width);
+    let (r, s) = (BigUint::from_bytes_be(r), BigUint::from_bytes_be(s));
+    if r < *order && s < *order {
+        Some((r, s))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scalar_from_bytes_reduced() {
+        let order = BigUint::from(19u32);
+
+        // 0x0102...40 as a 64-byte big-endian integer
+        let bytes: alloc::vec::Vec<u8> = (1u8..=64).collect();
+        let expected = BigUint::from_bytes_be(&bytes) % &order;
+
+        let scalar = Scalar::from_bytes_reduced(&bytes, &order);
+        assert_eq!(*scalar.value(), expected);
+        assert_eq!(*scalar.order(), order);
+
+        for bytes in [&[][..], &[18], &[19], &[0xff; 64]] {
+            let scalar = Scalar::from_bytes_reduced(bytes, &order);
+            assert!(*scalar.value() < order);
+        }
+        assert_eq!(Scalar::from_bytes_reduced(&[19], &order).into_value(), BigUint::from(0u32));
+    }
+
+    #[test]
+    fn test_hash_to_scalar() {
+        // SHA-256("abc") = ba7816bf...f20015ad, which is already below the secp256k1 order
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+        let digest = BigUint::parse_bytes(
+            b"BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD",
+            16
+        ).expect("Could not convert digest");
+        assert_eq!(*hash_to_scalar(b"abc", &n).value(), digest);
+
+        // a longer q takes the whole digest
+        let order = BigUint::from(1u32) << 300u32;
+        assert_eq!(*hash_to_scalar(b"abc", &order).value(), digest);
+
+        // a shorter q takes the leftmost bits(q) bits, here the top 5, before reducing:
+        // 0xba >> 3 = 23 = 4 mod 19
+        let order = BigUint::from(19u32);
+        assert_eq!(*hash_to_scalar(b"abc", &order).value(), BigUint::from(4u32));
+    }
+
+    #[test]
+    fn test_hash_to_scalar_with_domain() {
+        let order = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert order");
+        let message = b"Bob -> 1 BTC -> Alice";
+
+        let a = hash_to_scalar_with_domain(b"A", message, &order);
+        let b = hash_to_scalar_with_domain(b"B", message, &order);
+        assert_ne!(a, b);
+        assert_ne!(a, hash_to_scalar(message, &order));
+        assert!(*a.value() < order);
+
+        // the length prefix separates where the domain ends and the message starts
+        assert_ne!(
+            hash_to_scalar_with_domain(b"ab", b"c", &order),
+            hash_to_scalar_with_domain(b"a", b"bc", &order)
+        );
+        assert_eq!(
+            hash_to_scalar_with_domain(b"A", message, &order),
+            hash_to_scalar(&domain_separated(b"A", message), &order)
+        );
+    }
+
+    #[test]
+    fn test_scalar_hex_round_trip() {
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+        let private_key = BigUint::parse_bytes(
+            b"00000000000000000000000000000000000000000000000000000000DEADBEEF",
+            16
+        ).expect("Could not convert private_key");
+
+        let s = to_hex(&private_key, &n);
+        assert_eq!(
+            s,
+            "00000000000000000000000000000000000000000000000000000000deadbeef"
+        );
+        assert_eq!(from_hex(&s, &n), Some(private_key));
+    }
+
+    #[test]
+    fn test_signature_bytes_round_trip() {
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+        let signature = (BigUint::from(0xDEADBEEFu32), &n - BigUint::from(1u32));
+
+        let bytes = signature_to_bytes(&signature, &n);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(bytes[28..32], [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(bytes[..28], [0; 28]);
+        assert_eq!(signature_from_bytes(&bytes, &n), Some(signature));
+
+        let zero = (BigUint::from(0u32), BigUint::from(0u32));
+        assert_eq!(signature_to_bytes(&zero, &n), [0; 64]);
+    }
+
+    #[test]
+    fn test_signature_from_bytes_malformed() {
+        let n = BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16
+        ).expect("Could not convert n");
+
+        // wrong length
+        assert_eq!(signature_from_bytes(&[0; 63], &n), None);
+        assert_eq!(signature_from_bytes(&[0; 65], &n), None);
+        assert_eq!(signature_from_bytes(&[], &n), None);
+        // a component not less than the order
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&n.to_bytes_be());
+        assert_eq!(signature_from_bytes(&bytes, &n), None);
+        assert_eq!(signature_from_bytes(&[0xff; 64], &n), None);
+    }
+
+    #[test]
+    fn test_scalar_from_hex_malformed() {
+        let order = BigUint::from(19u32);
+
+        assert_eq!(from_hex("0a", &order), Some(BigUint::from(10u32)));
+        // wrong width
+        assert_eq!(from_hex("a", &order), None);
+        assert_eq!(from_hex("000a", &order), None);
+        // not hex
+        assert_eq!(from_hex("zz", &order), None);
+        // not less than the order
+        assert_eq!(from_hex("13", &order), None);
+    }
+}
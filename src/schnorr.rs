@@ -0,0 +1,410 @@
+use num_bigint::BigUint;
+
+use crate::scalar::hash_to_scalar_with_domain;
+use crate::{EcError, EllipticCurve, FiniteField, Point};
+
+pub fn sign(
+    ec: &EllipticCurve,
+    generator: &Point,
+    q_order: &BigUint,
+    private_key: &BigUint,
+    message: &[u8],
+    k: &BigUint,
+) -> (Point, BigUint) {
+    // sign_with_domain with an empty domain
+    sign_with_domain(ec, generator, q_order, private_key, b"", message, k)
+}
+
+pub fn verify(
+    ec: &EllipticCurve,
+    generator: &Point,
+    q_order: &BigUint,
+    public_key: &Point,
+    message: &[u8],
+    signature: &(Point, BigUint),
+) -> bool {
+    // verify_with_domain with an empty domain
+    verify_with_domain(ec, generator, q_order, public_key, b"", message, signature)
+}
+
+pub fn try_sign(
+    ec: &EllipticCurve,
+    generator: &Point,
+    q_order: &BigUint,
+    private_key: &BigUint,
+    message: &[u8],
+    k: &BigUint,
+) -> Result<(Point, BigUint), EcError> {
+    check_point(ec, generator)?;
+    for d in [private_key, k] {
+        if *d == BigUint::from(0u32) || d >= q_order {
+            return Err(EcError::ScalarOutOfRange(d.clone()));
+        }
+    }
+    Ok(sign(ec, generator, q_order, private_key, message, k))
+}
+
+pub fn try_verify(
+    ec: &EllipticCurve,
+    generator: &Point,
+    q_order: &BigUint,
+    public_key: &Point,
+    message: &[u8],
+    signature: &(Point, BigUint),
+) -> Result<(), EcError> {
+    check_point(ec, generator)?;
+    check_point(ec, public_key)?;
+    if verify(ec, generator, q_order, public_key, message, signature) {
+        Ok(())
+    } else {
+        Err(EcError::InvalidSignature)
+    }
+}
+
+fn check_point(ec: &EllipticCurve, c: &Point) -> Result<(), EcError> {
+    // generators and public keys must be affine points on the curve
+    if *c == Point::Identity {
+        Err(EcError::PointIsIdentity)
+    } else if !ec.is_on_curve(c) {
+        Err(EcError::NotOnCurve(c.clone()))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn sign_with_domain(
+    ec: &EllipticCurve,
+    generator: &Point,
+    q_order: &BigUint,
+    private_key: &BigUint,
+    domain: &[u8],
+    message: &[u8],
+    k: &BigUint,
+) -> (Point, BigUint) {
+    // R = k * G
+    // e = H(len(domain) || domain || R || P || m), truncated to bits(q) and reduced mod q
+    // s = k + e * d mod q
+    //
+    // the domain is part of e, so a signature made under one domain does not verify under
+    // another. k and d are not reduced: both must already be in [1, q), and anything else
+    // panics
+    let zero = BigUint::from(0u32);
+    assert!(*k != zero && k < q_order, "k should be in [1, q)");
+    assert!(*private_key != zero && private_key < q_order, "private key should be in [1, q)");
+
+    let r = ec.scalar_multiplication(generator, k);
+    let public_key = ec.scalar_multiplication(generator, private_key);
+    let e = challenge(ec, q_order, domain, &r, &public_key, message);
+
+    let ed = FiniteField::multiplication(&e, private_key, q_order);
+    let s = FiniteField::add(k, &ed, q_order);
+
+    (r, s)
+}
+
+pub fn verify_with_domain(
+    ec: &EllipticCurve,
+    generator: &Point,
+    q_order: &BigUint,
+    public_key: &Point,
+    domain: &[u8],
+    message: &[u8],
+    signature: &(Point, BigUint),
+) -> bool {
+    // s * G == R + e * P
+    // R and P are untrusted, is_on_curve rejects them when a coordinate is not reduced mod p.
+    // P = Identity would accept R = s * G for any s and message, and R = Identity is never
+    // produced by sign, so both are rejected
+    let (r, s) = signature;
+    if s >= q_order || *r == Point::Identity || *public_key == Point::Identity {
+        return false;
+    }
+    if !ec.is_on_curve(r) || !ec.is_on_curve(public_key) {
+        return false;
+    }
+
+    let e = challenge(ec, q_order, domain, r, public_key, message);
+
+    let sg = ec.scalar_multiplication(generator, s);
+    let ep = ec.scalar_multiplication(public_key, &e);
+    sg == ec.add(r, &ep)
+}
+
+fn challenge(
+    ec: &EllipticCurve,
+    q_order: &BigUint,
+    domain: &[u8],
+    r: &Point,
+    public_key: &Point,
+    message: &[u8],
+) -> BigUint {
+    // e = hash_to_scalar_with_domain(domain, R || P || m, q), where points are hashed in their
+    // fixed-width hex encoding
+    let mut bytes = r.to_hex(ec.modulus()).into_bytes();
+    bytes.extend_from_slice(public_key.to_hex(ec.modulus()).as_bytes());
+    bytes.extend_from_slice(message);
+    hash_to_scalar_with_domain(domain, &bytes, q_order).into_value()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+
+        let private_key = BigUint::from(7u32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        let message = b"Bob -> 1 BTC -> Alice";
+
+        for k in [1u32, 5, 13, 18] {
+            let signature = sign(&ec, &g, &q_order, &private_key, message, &BigUint::from(k));
+            assert!(verify(&ec, &g, &q_order, &public_key, message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_tampered_message() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+
+        let private_key = BigUint::from(7u32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+
+        // the challenge for both messages must differ for the tampering to be detectable,
+        // which is not guaranteed over a group this small
+        let message = b"Bob -> 1 BTC -> Alice";
+        let tampered_message = b"Bob -> 2 BTC -> Alice";
+        let signature = sign(&ec, &g, &q_order, &private_key, message, &BigUint::from(5u32));
+        let r = &signature.0;
+        assert_ne!(
+            challenge(&ec, &q_order, b"", r, &public_key, message),
+            challenge(&ec, &q_order, b"", r, &public_key, tampered_message)
+        );
+
+        assert!(!verify(&ec, &g, &q_order, &public_key, tampered_message, &signature));
+    }
+
+    #[test]
+    fn test_verify_out_of_range_points() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+
+        let private_key = BigUint::from(7u32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        let message = b"Bob -> 1 BTC -> Alice";
+        let (_, s) = sign(&ec, &g, &q_order, &private_key, message, &BigUint::from(5u32));
+
+        // (22, 1) is (5, 1) with x left unreduced
+        let r = Point::Coordinate(BigUint::from(22u32), BigUint::from(1u32));
+        assert!(!verify(&ec, &g, &q_order, &public_key, message, &(r, s.clone())));
+
+        // (5, 18) is (5, 1) with y left unreduced
+        let signature = sign(&ec, &g, &q_order, &BigUint::from(1u32), message, &BigUint::from(5u32));
+        let public_key = Point::Coordinate(BigUint::from(5u32), BigUint::from(18u32));
+        assert!(!verify(&ec, &g, &q_order, &public_key, message, &signature));
+    }
+
+    #[test]
+    fn test_verify_identity_public_key() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+        let message = b"Bob -> 1 BTC -> Alice";
+
+        // s * G == R + e * Identity for R = s * G, whatever the message
+        let s = BigUint::from(3u32);
+        let r = ec.scalar_multiplication(&g, &s);
+        assert!(!verify(&ec, &g, &q_order, &Point::Identity, message, &(r, s)));
+
+        // R = Identity with s = e * d
+        let private_key = BigUint::from(7u32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        let e = challenge(&ec, &q_order, b"", &Point::Identity, &public_key, message);
+        let s = FiniteField::multiplication(&e, &private_key, &q_order);
+        assert!(!verify(&ec, &g, &q_order, &public_key, message, &(Point::Identity, s)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sign_k_out_of_range() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+
+        sign(&ec, &g, &q_order, &BigUint::from(7u32), b"m", &BigUint::from(19u32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sign_private_key_out_of_range() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+
+        sign(&ec, &g, &q_order, &BigUint::from(26u32), b"m", &BigUint::from(5u32));
+    }
+
+    #[test]
+    fn test_try_sign() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+        let private_key = BigUint::from(7u32);
+        let message = b"Bob -> 1 BTC -> Alice";
+        let k = BigUint::from(5u32);
+
+        assert_eq!(
+            try_sign(&ec, &g, &q_order, &private_key, message, &k),
+            Ok(sign(&ec, &g, &q_order, &private_key, message, &k))
+        );
+
+        assert_eq!(
+            try_sign(&ec, &Point::Identity, &q_order, &private_key, message, &k),
+            Err(EcError::PointIsIdentity)
+        );
+        let off_curve = Point::Coordinate(BigUint::from(5u32), BigUint::from(2u32));
+        assert_eq!(
+            try_sign(&ec, &off_curve, &q_order, &private_key, message, &k),
+            Err(EcError::NotOnCurve(off_curve))
+        );
+        for d in [0u32, 19, 26] {
+            let d = BigUint::from(d);
+            assert_eq!(
+                try_sign(&ec, &g, &q_order, &d, message, &k),
+                Err(EcError::ScalarOutOfRange(d.clone()))
+            );
+            assert_eq!(
+                try_sign(&ec, &g, &q_order, &private_key, message, &d),
+                Err(EcError::ScalarOutOfRange(d))
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_verify() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+        let private_key = BigUint::from(7u32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        let message = b"Bob -> 1 BTC -> Alice";
+        let signature = sign(&ec, &g, &q_order, &private_key, message, &BigUint::from(5u32));
+
+        assert_eq!(try_verify(&ec, &g, &q_order, &public_key, message, &signature), Ok(()));
+
+        assert_eq!(
+            try_verify(&ec, &g, &q_order, &Point::Identity, message, &signature),
+            Err(EcError::PointIsIdentity)
+        );
+        assert_eq!(
+            try_verify(&ec, &Point::Identity, &q_order, &public_key, message, &signature),
+            Err(EcError::PointIsIdentity)
+        );
+        let off_curve = Point::Coordinate(BigUint::from(5u32), BigUint::from(18u32));
+        assert_eq!(
+            try_verify(&ec, &g, &q_order, &off_curve, message, &signature),
+            Err(EcError::NotOnCurve(off_curve))
+        );
+
+        // a mismatched signature, an s not less than q and R = Identity
+        let (r, s) = signature;
+        let tampered = (r.clone(), FiniteField::add(&s, &BigUint::from(1u32), &q_order));
+        for signature in [tampered, (r, q_order.clone()), (Point::Identity, s)] {
+            assert_eq!(
+                try_verify(&ec, &g, &q_order, &public_key, message, &signature),
+                Err(EcError::InvalidSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_with_domain() {
+        // y^2 = x^3 + 7 mod p (secp256k1)
+        let (ec, g, q_order) = crate::curves::secp256k1();
+
+        let private_key = BigUint::from(0xDEADBEEFu32);
+        let public_key = ec.scalar_multiplication(&g, &private_key);
+        let message = b"Bob -> 1 BTC -> Alice";
+        let k = BigUint::from(12345u32);
+
+        let signature = sign_with_domain(&ec, &g, &q_order, &private_key, b"A", message, &k);
+        assert!(verify_with_domain(&ec, &g, &q_order, &public_key, b"A", message, &signature));
+        assert!(!verify_with_domain(&ec, &g, &q_order, &public_key, b"B", message, &signature));
+        assert!(!verify(&ec, &g, &q_order, &public_key, message, &signature));
+
+        // sign and verify are the empty domain
+        let signature = sign(&ec, &g, &q_order, &private_key, message, &k);
+        assert_eq!(
+            signature,
+            sign_with_domain(&ec, &g, &q_order, &private_key, b"", message, &k)
+        );
+        assert!(verify_with_domain(&ec, &g, &q_order, &public_key, b"", message, &signature));
+    }
+
+    #[test]
+    fn test_verify_wrong_public_key() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let g = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+        let q_order = BigUint::from(19u32);
+
+        let message = b"Bob -> 1 BTC -> Alice";
+        let signature = sign(&ec, &g, &q_order, &BigUint::from(7u32), message, &BigUint::from(5u32));
+
+        let public_key = ec.scalar_multiplication(&g, &BigUint::from(8u32));
+        assert!(!verify(&ec, &g, &q_order, &public_key, message, &signature));
+
+        let (r, _) = signature;
+        assert!(!verify(&ec, &g, &q_order, &public_key, message, &(r, q_order.clone())));
+    }
+}
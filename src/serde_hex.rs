@@ -0,0 +1,18 @@
+use alloc::format;
+use alloc::string::String;
+
+use num_bigint::BigUint;
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+// BigUint values are (de)serialized as lowercase hex strings, e.g. 255 <-> "ff"
+
+pub fn serialize<S: Serializer>(value: &BigUint, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_str_radix(16))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigUint, D::Error> {
+    // owned rather than &str, so readers and values that cannot lend their input still work
+    let s = String::deserialize(deserializer)?;
+    BigUint::parse_bytes(s.as_bytes(), 16)
+        .ok_or_else(|| D::Error::custom(format!("invalid hex number: {s}")))
+}
@@ -0,0 +1,64 @@
+// Built only by `cargo test` with std, and ignored by default since it is slow and only
+// meaningful in an optimised build: run with `cargo test --release -- --ignored timing`.
+//
+// A heuristic guard, not a proof of constant time: it compares the median time of
+// scalar_multiplication_uniform for a low and a high Hamming weight scalar of the same bit
+// length, and fails if one is grossly slower, as it would be if the loop started branching
+// on the bits of d again.
+
+extern crate std;
+
+use std::time::{Duration, Instant};
+use std::vec::Vec;
+
+use num_bigint::BigUint;
+
+use crate::curves::secp256k1;
+use crate::Point;
+
+const ITERATIONS: usize = 15;
+// generous, so scheduler noise alone does not fail the test
+const MAX_RATIO: f64 = 1.25;
+
+fn medians<F: FnMut(usize)>(mut f: F) -> (Duration, Duration) {
+    // the two inputs take turns, after one untimed warm-up run each, so drift in the machine's
+    // speed over the run affects both alike
+    f(0);
+    f(1);
+
+    let mut times: [Vec<Duration>; 2] = [Vec::new(), Vec::new()];
+    for _ in 0..ITERATIONS {
+        for (input, times) in times.iter_mut().enumerate() {
+            let start = Instant::now();
+            f(input);
+            times.push(start.elapsed());
+        }
+    }
+    let [mut first, mut second] = times;
+    first.sort();
+    second.sort();
+    (first[ITERATIONS / 2], second[ITERATIONS / 2])
+}
+
+#[test]
+#[ignore]
+fn test_timing_scalar_multiplication_uniform() {
+    let (ec, g, n) = secp256k1();
+
+    // both 255 bits long: 2^254 + 1 has two bits set, 2^255 - 1 has all of them
+    let low: BigUint = (BigUint::from(1u32) << 254u32) + 1u32;
+    let high: BigUint = (BigUint::from(1u32) << 255u32) - 1u32;
+    assert_eq!(low.bits(), high.bits());
+
+    let scalars = [low, high];
+    let mut result = Point::Identity;
+    let (low_time, high_time) =
+        medians(|input| result = ec.scalar_multiplication_uniform(&g, &scalars[input], &n));
+    assert!(ec.is_on_curve(&result));
+
+    let ratio = high_time.as_secs_f64() / low_time.as_secs_f64();
+    assert!(
+        (1.0 / MAX_RATIO..=MAX_RATIO).contains(&ratio),
+        "low weight: {low_time:?}, high weight: {high_time:?}, ratio: {ratio:.2}"
+    );
+}
@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+
+use crate::{EllipticCurve, Point};
+
+// Step-by-step records of the addition law, for following along with the formulas by hand.
+// `add` and `double` themselves never record anything.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraceEvent {
+    Slope(BigUint),
+    X3(BigUint),
+    Y3(BigUint),
+}
+
+impl EllipticCurve {
+    pub fn add_traced(&self, c: &Point, d: &Point) -> (Point, Vec<TraceEvent>) {
+        // add doubles equal points, so their steps are those of the tangent, not the chord
+        if c == d {
+            return self.double_traced(c);
+        }
+        let result = self.add(c, d);
+
+        // only a chord through two affine points has intermediate steps; the Identity
+        // and P + (-P) cases are returned without any
+        let mut events = Vec::new();
+        if let (Point::Coordinate(x1, y1), Point::Coordinate(x2, y2), Point::Coordinate(x3, y3)) =
+            (c, d, &result)
+        {
+            events.push(TraceEvent::Slope(self.add_slope(x1, y1, x2, y2)));
+            events.push(TraceEvent::X3(x3.clone()));
+            events.push(TraceEvent::Y3(y3.clone()));
+        }
+        (result, events)
+    }
+
+    pub fn double_traced(&self, c: &Point) -> (Point, Vec<TraceEvent>) {
+        let result = self.double(c);
+
+        let mut events = Vec::new();
+        if let (Point::Coordinate(x, y), Point::Coordinate(x3, y3)) = (c, &result) {
+            events.push(TraceEvent::Slope(self.double_slope(x, y)));
+            events.push(TraceEvent::X3(x3.clone()));
+            events.push(TraceEvent::Y3(y3.clone()));
+        }
+        (result, events)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_add_traced() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let c = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+        let d = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        let (result, events) = ec.add_traced(&c, &d);
+
+        assert_eq!(result, ec.add(&c, &d));
+        assert_eq!(
+            events,
+            vec![
+                TraceEvent::Slope(BigUint::from(2u32)),
+                TraceEvent::X3(BigUint::from(10u32)),
+                TraceEvent::Y3(BigUint::from(6u32)),
+            ]
+        );
+
+        // (5, 1) + (5, 1) follows the tangent through (5, 1), see test_double_traced
+        let (result, events) = ec.add_traced(&d, &d);
+
+        assert_eq!(result, ec.add(&d, &d));
+        assert_eq!(
+            events,
+            vec![
+                TraceEvent::Slope(BigUint::from(13u32)),
+                TraceEvent::X3(BigUint::from(6u32)),
+                TraceEvent::Y3(BigUint::from(3u32)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_traced_identity() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let c = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        let (result, events) = ec.add_traced(&c, &Point::Identity);
+        assert_eq!(result, c);
+        assert!(events.is_empty());
+
+        let (result, events) = ec.add_traced(&c, &ec.negate(&c));
+        assert_eq!(result, Point::Identity);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_double_traced() {
+        // y^2 = x^3 + 2x + 2 mod 17
+        let ec = EllipticCurve::new(
+            BigUint::from(2u32),
+            BigUint::from(2u32),
+            BigUint::from(17u32),
+        );
+        let c = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+
+        // s = (3 * 25 + 2) / 2 = 77 / 2 = 13 mod 17
+        let (result, events) = ec.double_traced(&c);
+
+        assert_eq!(result, Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32)));
+        assert_eq!(
+            events,
+            vec![
+                TraceEvent::Slope(BigUint::from(13u32)),
+                TraceEvent::X3(BigUint::from(6u32)),
+                TraceEvent::Y3(BigUint::from(3u32)),
+            ]
+        );
+    }
+}
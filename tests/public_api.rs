@@ -0,0 +1,38 @@
+use ec_cryptography::{EllipticCurve, FiniteField, Point};
+use num_bigint::BigUint;
+
+#[test]
+fn test_finite_field_public_api() {
+    let c = BigUint::from(4u32);
+    let p = BigUint::from(17u32);
+
+    let c_inverse = FiniteField::inverse_multiplication(&c, &p);
+    assert_eq!(c_inverse, BigUint::from(13u32));
+
+    let c_negated = FiniteField::inverse_addition(&c, &p);
+    assert_eq!(c_negated, BigUint::from(13u32));
+
+    assert_eq!(FiniteField::pow(&c, &BigUint::from(2u32), &p), BigUint::from(16u32));
+    assert_eq!(FiniteField::legendre_symbol(&c, &p), 1);
+}
+
+#[test]
+fn test_elliptic_curve_public_api() {
+    // y^2 = x^3 + 2x + 2 mod 17
+    let ec = EllipticCurve::new(
+        BigUint::from(2u32),
+        BigUint::from(2u32),
+        BigUint::from(17u32),
+    );
+
+    let p1 = Point::Coordinate(BigUint::from(5u32), BigUint::from(1u32));
+    let p2 = Point::Coordinate(BigUint::from(6u32), BigUint::from(3u32));
+
+    assert!(ec.is_on_curve(&p1));
+    assert_eq!(ec.double(&p1), p2);
+    assert_eq!(
+        ec.add(&p1, &p2),
+        Point::Coordinate(BigUint::from(10u32), BigUint::from(6u32))
+    );
+    assert_eq!(ec.scalar_multiplication(&p1, &BigUint::from(19u32)), Point::Identity);
+}